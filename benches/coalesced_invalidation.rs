@@ -0,0 +1,163 @@
+//! Times a data change fanning out to many bound labels, eg a theme colour, and counts the layout passes it costs.
+//!
+//! Every host requests layout when it writes the colour to its label. Druid only records those requests
+//! as a flag on each widget, merged into its parent's on the way up the tree, so the root sees one request
+//! and runs one layout pass per change, however many hosts there are. What grows with the hosts is
+//! the writes and the labels laid out, which no batching across hosts can save.
+//!
+//! Run with `cargo bench --bench coalesced_invalidation`.
+
+use druid::tests::harness::Harness;
+use druid::widget::prelude::*;
+use druid::widget::{Flex, Label};
+use druid::{lens, Color, KeyOrValue, Point, Selector, WidgetPod};
+use druid_bindings::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Instant;
+
+type Colour = KeyOrValue<Color>;
+
+/// Set the bound colour
+const RECOLOUR: Selector<Color> = Selector::new("coalesced-invalidation.recolour");
+
+const CHANGES: usize = 200;
+
+/// Sets the colour on RECOLOUR, and counts the layout requests reaching it
+struct Root {
+    labels: WidgetPod<Colour, Flex<Colour>>,
+    requests: Rc<Cell<usize>>,
+}
+
+impl Widget<Colour> for Root {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Colour, env: &Env) {
+        match event {
+            Event::Command(cmd) if cmd.is(RECOLOUR) => {
+                *data = (*cmd.get_unchecked(RECOLOUR)).into();
+                ctx.set_handled()
+            }
+            _ => self.labels.event(ctx, event, data, env),
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &Colour, env: &Env) {
+        self.labels.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &Colour, data: &Colour, env: &Env) {
+        self.labels.update(ctx, data, env);
+        if self.labels.layout_requested() {
+            self.requests.set(self.requests.get() + 1)
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Colour,
+        env: &Env,
+    ) -> Size {
+        let size = self.labels.layout(ctx, bc, data, env);
+        self.labels.set_origin(ctx, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Colour, env: &Env) {
+        self.labels.paint(ctx, data, env)
+    }
+}
+
+/// Counts the times the bound label in it is laid out
+struct Counted<W> {
+    inner: W,
+    layouts: Rc<Cell<usize>>,
+}
+
+impl<W: Widget<Colour>> Widget<Colour> for Counted<W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Colour, env: &Env) {
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &Colour, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &Colour, data: &Colour, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Colour,
+        env: &Env,
+    ) -> Size {
+        self.layouts.set(self.layouts.get() + 1);
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Colour, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+fn bound_labels(hosts: usize, layouts: &Rc<Cell<usize>>) -> Flex<Colour> {
+    let mut labels = Flex::column();
+    for i in 0..hosts {
+        let label = Label::new(format!("Label {}", i)).binding(LensPropBinding::new(
+            lens::Identity,
+            LabelTextColorProperty::default(),
+        ));
+        labels.add_child(Counted {
+            inner: label,
+            layouts: layouts.clone(),
+        });
+    }
+    labels
+}
+
+fn run(hosts: usize) {
+    let requests = Rc::new(Cell::new(0));
+    let layouts = Rc::new(Cell::new(0));
+    let root = Root {
+        labels: WidgetPod::new(bound_labels(hosts, &layouts)),
+        requests: requests.clone(),
+    };
+    let colour: Colour = Color::BLACK.into();
+    Harness::create_simple(colour, root, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        requests.set(0);
+        layouts.set(0);
+
+        let start = Instant::now();
+        for change in 0..CHANGES {
+            // Every change is to a new colour, starting from the black the labels already have
+            let grey = (change % 255 + 1) as u8;
+            let requested = requests.get();
+            harness.submit_command(RECOLOUR.with(Color::grey8(grey)));
+            // As a window would, only lay out when the root was asked to
+            if requests.get() > requested {
+                harness.just_layout();
+            }
+        }
+        let elapsed = start.elapsed();
+
+        println!(
+            "{:>4} hosts: {} changes made {} layout requests reach the root, laying out {} labels, {:>8.1}µs per change",
+            hosts,
+            CHANGES,
+            requests.get(),
+            layouts.get(),
+            elapsed.as_secs_f64() * 1e6 / CHANGES as f64
+        );
+    });
+}
+
+fn main() {
+    for hosts in [1, 10, 50, 200] {
+        run(hosts)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::binding::Binding;
+use druid::{Data, Env, EventCtx, Lens, UpdateCtx};
+use std::cell::Cell;
+
+/// This wraps another binding, choosing which side initialises the other when its host is added:
+/// where unset(data) holds, eg the field is still at its default, the widget's own state is adopted into the data,
+/// otherwise the data is written to the widget. This suits restoring persisted state when there is some,
+/// and taking the widget's defaults when there isn't. Build the predicate with field_is_default or field_is.
+///
+/// Changes from the widget are held back until the choice has been made, so that a widget
+/// reporting its defaults early can't overwrite a restored value.
+pub struct AdoptIfUnsetBinding<B, F> {
+    binding: B,
+    unset: F,
+    initialised: Cell<bool>,
+}
+
+impl<B, F> AdoptIfUnsetBinding<B, F> {
+    /// Wrap binding, adopting the widget's state where unset holds on the data
+    pub fn new(binding: B, unset: F) -> Self {
+        AdoptIfUnsetBinding {
+            binding,
+            unset,
+            initialised: Cell::new(false),
+        }
+    }
+}
+
+/// A predicate for adopt_if_unset: whether the field at lens is still its Default
+pub fn field_is_default<T, V: Data + Default, L: Lens<T, V>>(lens: L) -> impl Fn(&T) -> bool {
+    let default = V::default();
+    move |data| lens.with(data, |field| field.same(&default))
+}
+
+/// A predicate for adopt_if_unset: whether the field at lens holds sentinel, eg a NaN or an empty name
+pub fn field_is<T, V: Data, L: Lens<T, V>>(lens: L, sentinel: V) -> impl Fn(&T) -> bool {
+    move |data| lens.with(data, |field| field.same(&sentinel))
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>, F: Fn(&T) -> bool> Binding<T, Controlled>
+    for AdoptIfUnsetBinding<B, F>
+{
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        if self.initialised.get() {
+            self.binding
+                .append_change_required(controlled, data, change, env);
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_change_to_data(controlled, data, change, ctx, env);
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        true
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        // When the data is unset, leave the widget alone: its state is picked up as a change once initialised
+        if !(self.unset)(data) {
+            self.binding
+                .apply_data_to_controlled(data, controlled, ctx, env);
+        }
+        self.initialised.set(true);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.adopt_if_unset(..)", self.binding.describe())
+    }
+}
@@ -0,0 +1,167 @@
+use druid::widget::prelude::*;
+use druid::{theme, Color, Insets, Point, TextLayout};
+use std::marker::PhantomData;
+
+const BADGE_PADDING: f64 = 3.;
+
+/// Wraps a widget, drawing a badge over its top right corner, eg an unread count on an inbox button.
+/// Bind BadgeTextProperty or BadgeCountProperty for what it says, and BadgeVisibleProperty for whether it shows.
+/// The badge sticks out past the corner of the widget, so leave room around it.
+pub struct Badged<T, W> {
+    inner: W,
+    text: TextLayout<String>,
+    visible: bool,
+    color: Color,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> Badged<T, W> {
+    /// Badge inner, with no badge showing to begin with
+    pub fn new(inner: W) -> Self {
+        let mut text = TextLayout::new();
+        text.set_text(String::new());
+        text.set_text_size(theme::TEXT_SIZE_NORMAL);
+        text.set_text_color(Color::WHITE);
+        Badged {
+            inner,
+            text,
+            visible: false,
+            color: Color::rgb8(0xd0, 0x30, 0x30),
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// Builder-style method for the colour behind the badge text
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// The badge text
+    pub fn badge_text(&self) -> &str {
+        self.text.text().map(|text| text.as_str()).unwrap_or("")
+    }
+
+    /// Set the badge text, without changing whether it shows
+    pub fn set_badge_text(&mut self, text: String) {
+        self.text.set_text(text)
+    }
+
+    /// Show count as the badge text, showing the badge only when count isn't zero
+    pub fn set_count(&mut self, count: u64) {
+        self.text.set_text(count.to_string());
+        self.visible = count > 0;
+    }
+
+    /// Whether the badge shows
+    pub fn is_badge_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the badge
+    pub fn set_badge_visible(&mut self, visible: bool) {
+        self.visible = visible
+    }
+
+    /// The size of the badge, which is round for short text
+    fn badge_size(&self) -> Size {
+        let text = self.text.size();
+        let height = text.height + BADGE_PADDING;
+        Size::new((text.width + 2. * BADGE_PADDING).max(height), height)
+    }
+}
+
+impl<T, W> crate::BindableAccess for Badged<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for Badged<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if ctx.env_changed() && self.text.needs_rebuild_after_update(ctx) {
+            ctx.request_layout();
+        }
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.inner.layout(ctx, bc, data, env);
+        self.text.rebuild_if_needed(ctx.text(), env);
+        // The badge is centred on the corner, so half of it is outside of the widget
+        let badge = self.badge_size();
+        ctx.set_paint_insets(Insets::new(0., badge.height / 2., badge.width / 2., 0.));
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env);
+        if self.visible {
+            let badge = self.badge_size();
+            let rect = badge.to_rect().with_origin(Point::new(
+                ctx.size().width - badge.width / 2.,
+                -badge.height / 2.,
+            ));
+            ctx.fill(rect.to_rounded_rect(badge.height / 2.), &self.color);
+            let text = self.text.size();
+            self.text.draw(ctx, rect.center() - text.to_vec2() / 2.);
+        }
+    }
+}
+
+bindable_props! {
+    impl<T, W: Widget<T>> for Badged<T, W> {
+        /// A write-only property for the text of a Badged widget's badge
+        pub BadgeTextProperty: String => set_badge_text, request_layout;
+        /// A write-only property showing a count in a Badged widget's badge, hiding it at zero
+        pub BadgeCountProperty: u64 => set_count, request_layout;
+        /// A write-only property for whether a Badged widget's badge shows
+        pub BadgeVisibleProperty: bool => set_badge_visible, request_paint;
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockWidget, PropertyHarness};
+
+    fn badged() -> Badged<u64, MockWidget<()>> {
+        Badged::new(MockWidget::new(()))
+    }
+
+    #[test]
+    fn the_badge_text_is_written_to_the_widget() {
+        PropertyHarness::run(
+            BadgeTextProperty::default(),
+            Badged::<String, _>::new(MockWidget::new(())),
+            "new".to_string(),
+            |harness| {
+                assert_eq!(harness.controlled().badge_text(), "new");
+                harness.write("3".into());
+                assert_eq!(harness.controlled().badge_text(), "3");
+                // The badge is write-only, so nothing is read back from it
+                harness.controlled_mut().set_badge_text("changed".into());
+                assert!(!harness.read_back());
+                assert_eq!(harness.field(), "3");
+            },
+        );
+    }
+
+    #[test]
+    fn counts_show_the_badge_unless_zero() {
+        PropertyHarness::run(BadgeCountProperty::default(), badged(), 0, |harness| {
+            assert!(!harness.controlled().is_badge_visible());
+            harness.write(12);
+            assert!(harness.controlled().is_badge_visible());
+            assert_eq!(harness.controlled().badge_text(), "12");
+            harness.write(0);
+            assert!(!harness.controlled().is_badge_visible());
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use crate::binding::BindableProperty;
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::{Point, WidgetPod};
+use std::marker::PhantomData;
+
+/// Wraps a widget, recording the baseline offset it reports after each layout,
+/// so that BaselineOffsetProperty can bind it to the data for containers elsewhere to align to.
+/// The baseline is passed on to the wrapper's own parent unchanged.
+pub struct BaselineProbe<T, W> {
+    inner: WidgetPod<T, W>,
+    baseline: f64,
+}
+
+impl<T, W: Widget<T>> BaselineProbe<T, W> {
+    /// Record the baseline of inner
+    pub fn new(inner: W) -> Self {
+        BaselineProbe {
+            inner: WidgetPod::new(inner),
+            baseline: 0.,
+        }
+    }
+
+    /// The distance from the bottom of the widget to its baseline, as of its last layout
+    pub fn baseline(&self) -> f64 {
+        self.baseline
+    }
+}
+
+impl<T, W> crate::BindableAccess for BaselineProbe<T, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for BaselineProbe<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.inner.layout(ctx, bc, data, env);
+        compat::set_origin(&mut self.inner, ctx, data, env, Point::ORIGIN);
+        self.baseline = self.inner.baseline_offset();
+        ctx.set_baseline_offset(self.baseline);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// A read-only property exposing the baseline offset of the widget in a BaselineProbe.
+/// The host picks up changes after layout, so the data follows the baseline through relayouts.
+pub struct BaselineOffsetProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for BaselineOffsetProperty<T, W> {
+    fn default() -> Self {
+        BaselineOffsetProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BaselineOffsetProperty<T, W> {
+    type Controlled = BaselineProbe<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.baseline().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.baseline()
+    }
+}
@@ -0,0 +1,31 @@
+/// Bind a lens on the data to a property, with the direction of the binding written between them:
+///
+/// ```ignore
+/// // data to widget only
+/// bind!(AppState::alignment => LabelTextAlignmentProperty::default())
+/// // both ways
+/// bind!(AppState::offset <=> ScrollToProperty::new(Axis::Vertical))
+/// // widget to data only
+/// bind!(AppState::at_end <= ScrollEdgeProperty::new(Axis::Vertical))
+/// ```
+///
+/// These expand to `lens.bind(prop)` followed by `.forward()` or `.back()` as needed,
+/// so the arrow always points the way the value flows.
+#[macro_export]
+macro_rules! bind {
+    (@split [$($lens:tt)+] <=> $($prop:tt)+) => {
+        $crate::LensBindingExt::bind($($lens)+, $($prop)+)
+    };
+    (@split [$($lens:tt)+] => $($prop:tt)+) => {
+        $crate::BindingExt::forward($crate::LensBindingExt::bind($($lens)+, $($prop)+))
+    };
+    (@split [$($lens:tt)+] <= $($prop:tt)+) => {
+        $crate::BindingExt::back($crate::LensBindingExt::bind($($lens)+, $($prop)+))
+    };
+    (@split [$($lens:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::bind!(@split [$($lens)* $next] $($rest)*)
+    };
+    ($($tokens:tt)+) => {
+        $crate::bind!(@split [] $($tokens)+)
+    };
+}
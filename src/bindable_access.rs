@@ -1,3 +1,8 @@
+#[cfg(feature = "druid-master")]
+use druid::widget::{ControllerHost, EnvScope, IdentityWrapper, LensWrap, Padding, WidgetWrapper};
+#[cfg(feature = "druid-master")]
+use druid::Widget;
+
 /// This trait indicates that a class is a wrapper of another widget that may have API you wish to access.
 /// Used by BindingHost to "reach inside" things like LensWrapped in order to find the right widget to control,
 /// given the bindings that it has.
@@ -46,4 +51,37 @@ macro_rules! bindable_self_body{
             self
         }
     }
-}
\ No newline at end of file
+}
+
+// Reaching through these relies on WidgetWrapper, which druid 0.7 doesn't have.
+// Against 0.7, apply them after the binding like the wrappers below.
+#[cfg(feature = "druid-master")]
+impl<W: BindableAccess> BindableAccess for IdentityWrapper<W> {
+    bindable_wrapper_body!();
+}
+
+#[cfg(feature = "druid-master")]
+impl<T, U, L, W: BindableAccess> BindableAccess for LensWrap<T, U, L, W> {
+    bindable_wrapper_body!();
+}
+
+#[cfg(feature = "druid-master")]
+impl<T, W: Widget<T> + BindableAccess> BindableAccess for Padding<T, W> {
+    bindable_wrapper_body!();
+}
+
+#[cfg(feature = "druid-master")]
+impl<T, W: Widget<T> + BindableAccess> BindableAccess for EnvScope<T, W> {
+    bindable_wrapper_body!();
+}
+
+#[cfg(feature = "druid-master")]
+impl<W: BindableAccess, C> BindableAccess for ControllerHost<W, C> {
+    bindable_wrapper_body!();
+}
+
+// Container, SizedBox and Align hold their child as a Box<dyn Widget<T>>, so the concrete type needed
+// to reach its properties is erased. Apply those wrappers after the binding instead,
+// eg `scroll.binding(...).fix_height(...)`.
+// DisabledIf has no WidgetWrapper impl to reach its child through, so it also goes after the binding,
+// eg `slider.binding(...).disabled_if(...)`.
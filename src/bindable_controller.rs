@@ -0,0 +1,149 @@
+use crate::binding::BindableProperty;
+use druid::widget::prelude::*;
+use druid::widget::Controller;
+use druid::Data;
+use std::marker::PhantomData;
+
+/// The state of a Controller that can be bound with ControllerStateProperty, eg the count of a click counter
+/// or the timeout of a hover timer. Expose just the fields worth binding, as one Data value:
+///
+/// ```ignore
+/// impl ControllerState for HoverTimer {
+///     type State = (bool, f64);
+///     fn state(&self) -> Self::State {
+///         (self.hovering, self.delay_secs)
+///     }
+///     fn set_state(&mut self, state: &Self::State) {
+///         self.delay_secs = state.1
+///     }
+/// }
+/// ```
+pub trait ControllerState {
+    /// The bindable part of the controller's state
+    type State: Data;
+    /// Read the state from the controller
+    fn state(&self) -> Self::State;
+    /// Write the state from the data to the controller.
+    /// Parts of the state the controller only reports, and doesn't take from the data, are ignored here.
+    /// By default all of it is ignored, for controllers that are only observed.
+    fn set_state(&mut self, _state: &Self::State) {}
+}
+
+/// A ControllerHost whose controller can be bound, rather than the widget it controls.
+/// Druid's ControllerHost hands its bindings through to the child, so use this one where
+/// the behaviour to bind lives in the controller.
+pub struct BindableController<W, C> {
+    widget: W,
+    controller: C,
+}
+
+impl<W, C> BindableController<W, C> {
+    /// Control widget with controller
+    pub fn new(widget: W, controller: C) -> Self {
+        BindableController { widget, controller }
+    }
+
+    /// The controller
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// The controller, mutably
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// The controlled widget
+    pub fn widget(&self) -> &W {
+        &self.widget
+    }
+}
+
+impl<W, C> crate::BindableAccess for BindableController<W, C> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>, C: Controller<T, W>> Widget<T> for BindableController<W, C> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.controller
+            .event(&mut self.widget, ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.controller
+            .lifecycle(&mut self.widget, ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.controller
+            .update(&mut self.widget, ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.widget.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.widget.paint(ctx, data, env)
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.widget.id()
+    }
+}
+
+/// A two way bindable property for the ControllerState of a BindableController's controller.
+/// Writes request a layout, as it isn't known what the controller does with its state.
+pub struct ControllerStateProperty<W, C> {
+    phantom_w: PhantomData<W>,
+    phantom_c: PhantomData<C>,
+}
+
+impl<W, C> Default for ControllerStateProperty<W, C> {
+    fn default() -> Self {
+        ControllerStateProperty {
+            phantom_w: Default::default(),
+            phantom_c: Default::default(),
+        }
+    }
+}
+
+impl<W, C: ControllerState> BindableProperty for ControllerStateProperty<W, C> {
+    type Controlled = BindableController<W, C>;
+    type Value = C::State;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.controller.set_state(field_val);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.controller.state().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.controller.state()
+    }
+}
@@ -0,0 +1,62 @@
+use druid::widget::prelude::*;
+
+/// A widget that paints with a closure, like druid's Painter, which is also given a style value V.
+/// Binding PainterStyleProperty to the style keeps styling for custom drawing out of the
+/// data of the subtree being drawn: the painter repaints whenever the style is written.
+pub struct BindablePainter<T, V> {
+    style: V,
+    paint: Box<PaintFn<T, V>>,
+}
+
+type PaintFn<T, V> = dyn Fn(&mut PaintCtx, &T, &V, &Env);
+
+impl<T, V> BindablePainter<T, V> {
+    /// Paint with the paint closure, starting out with the given style
+    pub fn new(style: V, paint: impl Fn(&mut PaintCtx, &T, &V, &Env) + 'static) -> Self {
+        BindablePainter {
+            style,
+            paint: Box::new(paint),
+        }
+    }
+
+    /// The current style
+    pub fn style(&self) -> &V {
+        &self.style
+    }
+
+    /// Set the style. The painter must be repainted for it to take effect.
+    pub fn set_style(&mut self, style: V) {
+        self.style = style
+    }
+}
+
+impl<T, V> crate::BindableAccess for BindablePainter<T, V> {
+    bindable_self_body!();
+}
+
+impl<T: Data, V> Widget<T> for BindablePainter<T, V> {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {}
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, _env: &Env) {
+        if !old_data.same(data) {
+            ctx.request_paint();
+        }
+    }
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
+        bc.max()
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        (self.paint)(ctx, data, &self.style, env)
+    }
+}
+
+bindable_props! {
+    impl<T, V: Data> for BindablePainter<T, V> {
+        /// A write-only property for the style a BindablePainter paints with
+        pub PainterStyleProperty: V => set_style, request_paint;
+    }
+}
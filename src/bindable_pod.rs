@@ -0,0 +1,72 @@
+use crate::compat;
+use crate::BindableAccess;
+use druid::widget::prelude::*;
+use druid::{Point, WidgetPod};
+
+/// Owns a widget in a WidgetPod, while still giving bindings access to it.
+///
+/// Containers usually hold their children in WidgetPods, which hides the concrete widget from
+/// BindableAccess. A container that wants its child to be bindable can hold a BindablePod instead,
+/// or put a BindablePod between a BindingHost and the child so the child gets its own widget id.
+///
+/// Properties written through the host make their paint and layout requests on the host,
+/// which contains the pod, so those are honoured. Animation frames requested that way reach
+/// the host but are not forwarded into the pod; bind animating widgets outside of a pod.
+pub struct BindablePod<T, W> {
+    pod: WidgetPod<T, W>,
+}
+
+impl<T, W: Widget<T>> BindablePod<T, W> {
+    /// Put a widget in a pod
+    pub fn new(inner: W) -> Self {
+        BindablePod {
+            pod: WidgetPod::new(inner),
+        }
+    }
+
+    /// Access the pod
+    pub fn pod(&self) -> &WidgetPod<T, W> {
+        &self.pod
+    }
+
+    /// Mutably access the pod
+    pub fn pod_mut(&mut self) -> &mut WidgetPod<T, W> {
+        &mut self.pod
+    }
+}
+
+impl<T, W: Widget<T> + BindableAccess> BindableAccess for BindablePod<T, W> {
+    type Wrapped = W::Wrapped;
+
+    fn bindable(&self) -> &Self::Wrapped {
+        self.pod.widget().bindable()
+    }
+
+    fn bindable_mut(&mut self) -> &mut Self::Wrapped {
+        self.pod.widget_mut().bindable_mut()
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for BindablePod<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.pod.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.pod.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.pod.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.pod.layout(ctx, bc, data, env);
+        compat::set_origin(&mut self.pod, ctx, data, env, Point::ORIGIN);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.pod.paint(ctx, data, env)
+    }
+}
@@ -0,0 +1,414 @@
+use crate::binding::BindableProperty;
+use crate::change::Conflate;
+use crate::compat;
+use druid::text::{Attribute, RichText};
+use druid::widget::prelude::*;
+use druid::widget::RawLabel;
+use druid::{Data, FontWeight, Point, WidgetPod};
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A RawLabel showing RichText built from a String it holds itself, with the matches of a search query highlighted.
+/// A RawLabel shows its data, which a property can't change, so this builds the RichText for one instead.
+///
+/// Bind RichLabelTextProperty for the text, RichLabelSearchProperty for the query,
+/// and RichLabelMatchCountProperty to show how many matches there are, eg for an in-document search bar.
+/// Bind RichLabelPatchesProperty to lay annotations such as spell check squiggles over the text.
+pub struct BindableRichLabel<T> {
+    text: Arc<str>,
+    rich: RichText,
+    label: WidgetPod<RichText, RawLabel<RichText>>,
+    query: String,
+    highlight: Vec<Attribute>,
+    matches: Vec<Range<usize>>,
+    patches: AttributePatches,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> BindableRichLabel<T> {
+    /// An empty label, highlighting matches in bold
+    pub fn new() -> Self {
+        Self::from_raw_label(RawLabel::new())
+    }
+
+    /// Build the text for an already configured RawLabel
+    pub fn from_raw_label(label: RawLabel<RichText>) -> Self {
+        let text: Arc<str> = "".into();
+        BindableRichLabel {
+            rich: RichText::new(text.clone()),
+            text,
+            label: WidgetPod::new(label),
+            query: String::new(),
+            highlight: vec![Attribute::weight(FontWeight::BOLD)],
+            matches: Vec::new(),
+            patches: AttributePatches::default(),
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// Builder-style method for the attributes applied to matches of the query
+    pub fn with_highlight(mut self, highlight: Vec<Attribute>) -> Self {
+        self.highlight = highlight;
+        self.rebuild();
+        self
+    }
+
+    /// The text shown
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text shown. It is shown at the next update.
+    pub fn set_text(&mut self, text: String) {
+        self.text = text.into();
+        self.rebuild()
+    }
+
+    /// The search query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Highlight the matches of query, which are found case sensitively. An empty query matches nothing.
+    pub fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.rebuild()
+    }
+
+    /// The byte ranges of the text matching the query
+    pub fn matches(&self) -> &[Range<usize>] {
+        &self.matches
+    }
+
+    /// The number of matches of the query
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// The attribute patches laid over the text
+    pub fn patches(&self) -> &AttributePatches {
+        &self.patches
+    }
+
+    /// Lay patches over the text. When they only add to the patches already laid,
+    /// just the new ones are applied, otherwise the RichText is built again.
+    pub fn set_patches(&mut self, patches: AttributePatches) {
+        if patches.extends(&self.patches) {
+            let applied = self.patches.len();
+            self.patches = patches;
+            let len = self.text.len();
+            for patch in self.patches.iter().skip(applied) {
+                patch.apply_to(&mut self.rich, len);
+            }
+        } else {
+            self.patches = patches;
+            self.rebuild()
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.matches = if self.query.is_empty() {
+            Vec::new()
+        } else {
+            self.text
+                .match_indices(self.query.as_str())
+                .map(|(start, found)| start..start + found.len())
+                .collect()
+        };
+        let mut rich = RichText::new(self.text.clone());
+        for range in &self.matches {
+            for attribute in &self.highlight {
+                rich.add_attribute(range.clone(), attribute.clone());
+            }
+        }
+        for patch in self.patches.iter() {
+            patch.apply_to(&mut rich, self.text.len());
+        }
+        self.rich = rich;
+    }
+}
+
+impl<T> Default for BindableRichLabel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> crate::BindableAccess for BindableRichLabel<T> {
+    bindable_self_body!();
+}
+
+impl<T: Data> Widget<T> for BindableRichLabel<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut T, env: &Env) {
+        let mut rich = self.rich.clone();
+        self.label.event(ctx, event, &mut rich, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, env: &Env) {
+        self.label.lifecycle(ctx, event, &self.rich, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, env: &Env) {
+        self.label.update(ctx, &self.rich, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        let size = self.label.layout(ctx, bc, &self.rich, env);
+        compat::set_origin(&mut self.label, ctx, &self.rich, env, Point::ORIGIN);
+        ctx.set_baseline_offset(self.label.baseline_offset());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        self.label.paint(ctx, &self.rich, env)
+    }
+}
+
+bindable_props! {
+    impl<T: Data> for BindableRichLabel<T> {
+        /// A write-only property for the text of a BindableRichLabel
+        pub RichLabelTextProperty: String => set_text, request_layout;
+        /// A write-only property for the search query highlighted in a BindableRichLabel
+        pub RichLabelSearchProperty: String => set_query, request_layout;
+    }
+}
+
+/// One attribute laid over a byte range of a BindableRichLabel's text
+#[derive(Debug, Clone)]
+pub struct AttributePatch {
+    /// The byte range covered. Any part of it past the end of the text is ignored.
+    pub range: Range<usize>,
+    /// The attribute applied over the range
+    pub attribute: Attribute,
+}
+
+impl AttributePatch {
+    /// Cover range with attribute
+    pub fn new(range: Range<usize>, attribute: Attribute) -> Self {
+        AttributePatch { range, attribute }
+    }
+
+    fn apply_to(&self, rich: &mut RichText, len: usize) {
+        let range = self.range.start.min(len)..self.range.end.min(len);
+        if !range.is_empty() {
+            rich.add_attribute(range, self.attribute.clone());
+        }
+    }
+}
+
+/// The attribute patches laid over a BindableRichLabel's text, in the order they are applied.
+/// Patches pushed onto the patches the label already has are applied incrementally, so an annotation
+/// layer can grow without the whole RichText being built again. Start again from empty patches to remove some.
+///
+/// As a change, these are a batch of patches to add: a later batch is appended when they are conflated,
+/// unless it already starts with this one, in which case it replaces it.
+#[derive(Debug, Clone, Default)]
+pub struct AttributePatches {
+    patches: Arc<Vec<Arc<AttributePatch>>>,
+}
+
+impl AttributePatches {
+    /// Add patch, applied after those already here
+    pub fn push(&mut self, patch: AttributePatch) {
+        Arc::make_mut(&mut self.patches).push(Arc::new(patch))
+    }
+
+    /// The patches, in the order they are applied
+    pub fn iter(&self) -> impl Iterator<Item = &AttributePatch> {
+        self.patches.iter().map(|patch| &**patch)
+    }
+
+    /// The number of patches
+    pub fn len(&self) -> usize {
+        self.patches.len()
+    }
+
+    /// Whether there are no patches
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Whether these are the earlier patches with more pushed onto them, or the same patches.
+    /// Patches are compared by identity, as attributes can't be compared.
+    pub fn extends(&self, earlier: &AttributePatches) -> bool {
+        earlier.len() <= self.len()
+            && earlier
+                .patches
+                .iter()
+                .zip(self.patches.iter())
+                .all(|(e, p)| Arc::ptr_eq(e, p))
+    }
+}
+
+impl Data for AttributePatches {
+    fn same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.patches, &other.patches) || (self.extends(other) && other.extends(self))
+    }
+}
+
+impl Conflate for AttributePatches {
+    fn conflate(&mut self, later: Self) {
+        if later.extends(self) {
+            *self = later
+        } else {
+            Arc::make_mut(&mut self.patches).extend(later.patches.iter().cloned())
+        }
+    }
+}
+
+/// A write-only property for the attribute patches laid over a BindableRichLabel's text
+pub struct RichLabelPatchesProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> Default for RichLabelPatchesProperty<T> {
+    fn default() -> Self {
+        RichLabelPatchesProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T: Data> BindableProperty for RichLabelPatchesProperty<T> {
+    type Controlled = BindableRichLabel<T>;
+    type Value = AttributePatches;
+    type Change = AttributePatches;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        if !controlled.patches().same(field_val) {
+            controlled.set_patches(field_val.clone());
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
+
+/// A read-only property exposing the number of matches of a BindableRichLabel's search query
+pub struct RichLabelMatchCountProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> Default for RichLabelMatchCountProperty<T> {
+    fn default() -> Self {
+        RichLabelMatchCountProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T: Data> BindableProperty for RichLabelMatchCountProperty<T> {
+    type Controlled = BindableRichLabel<T>;
+    type Value = usize;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.match_count() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.match_count()
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::PropertyHarness;
+
+    fn searching(query: &str) -> BindableRichLabel<String> {
+        let mut label = BindableRichLabel::new();
+        label.set_query(query.into());
+        label
+    }
+
+    #[test]
+    fn the_text_is_written_to_the_label() {
+        PropertyHarness::run(
+            RichLabelTextProperty::default(),
+            searching("an"),
+            "banana".to_string(),
+            |harness| {
+                assert_eq!(harness.controlled().text(), "banana");
+                assert_eq!(harness.controlled().matches(), &[1..3, 3..5]);
+                harness.write("cantaloupe".into());
+                assert_eq!(harness.controlled().text(), "cantaloupe");
+                assert_eq!(harness.controlled().matches().len(), 1);
+                assert_eq!(harness.controlled().matches()[0], 1..3);
+                // The label is write-only, so nothing is read back from it
+                harness.controlled_mut().set_text("changed".into());
+                assert!(!harness.read_back());
+                assert_eq!(harness.field(), "cantaloupe");
+            },
+        );
+    }
+
+    #[test]
+    fn the_match_count_follows_the_query() {
+        let mut label = searching("an");
+        label.set_text("banana".into());
+        PropertyHarness::run(
+            RichLabelMatchCountProperty::default(),
+            label,
+            0,
+            |harness| {
+                assert!(harness.read_back());
+                assert_eq!(*harness.field(), 2);
+                harness.controlled_mut().set_query("nan".into());
+                assert!(harness.read_back());
+                assert_eq!(*harness.field(), 1);
+                // Writing the count does nothing
+                harness.write(5);
+                assert_eq!(harness.controlled().match_count(), 1);
+            },
+        );
+    }
+}
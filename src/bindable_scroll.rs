@@ -0,0 +1,644 @@
+use crate::binding::BindableProperty;
+use crate::compat;
+use druid::scroll_component::ScrollComponent;
+use druid::widget::prelude::*;
+use druid::widget::{Axis, Viewport};
+#[cfg(not(feature = "druid-master"))]
+use druid::Affine;
+use druid::{Data, Point, Rect, TimerToken, UnitPoint, WidgetPod};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long scrolling has to pause before a BindableScroll settles on a snap point
+const SETTLE_DELAY: Duration = Duration::from_millis(150);
+
+/// The offsets on an axis that a BindableScroll settles on once scrolling stops
+#[derive(Debug, Clone, Default, PartialEq, Data)]
+pub enum SnapPoints {
+    /// Stop wherever scrolling leaves off
+    #[default]
+    None,
+    /// Every multiple of the interval, eg the width of a page
+    Interval(f64),
+    /// Just these offsets
+    Points(Arc<Vec<f64>>),
+}
+
+impl SnapPoints {
+    /// The snap point nearest to position, where no offset can exceed limit.
+    /// The limit itself is a snap point for Interval, so the end of the content can always be reached.
+    /// Of two points as near as each other, the lower multiple of an Interval or the earlier of the Points wins.
+    /// Points that aren't finite are skipped, and a position that isn't a number is left where it is.
+    pub fn nearest(&self, position: f64, limit: f64) -> f64 {
+        if position.is_nan() {
+            return position;
+        }
+        let candidates = match self {
+            SnapPoints::None => return position,
+            SnapPoints::Interval(interval) if interval.is_finite() && *interval > 0. => {
+                let below = (position / interval).floor() * interval;
+                vec![below, below + interval]
+            }
+            SnapPoints::Interval(_) => return position,
+            SnapPoints::Points(points) => points.to_vec(),
+        };
+        candidates
+            .into_iter()
+            .filter(|point| point.is_finite())
+            .map(|point| point.max(0.).min(limit))
+            .min_by(|a, b| (a - position).abs().total_cmp(&(b - position).abs()))
+            .unwrap_or(position)
+    }
+}
+
+/// A scroll container that keeps its own offset, so that it can be bound without the accessors
+/// that only druid's master Scroll has. It scrolls with the wheel and shows druid's usual scrollbars.
+///
+/// Bind BindableScrollOffsetProperty, BindableScrollLimitProperty and BindableScrollViewportProperty
+/// to it as you would the Scroll properties.
+pub struct BindableScroll<T, W> {
+    child: WidgetPod<T, W>,
+    scroll_component: ScrollComponent,
+    port: Viewport,
+    direction: Option<Axis>,
+    snap_horizontal: SnapPoints,
+    snap_vertical: SnapPoints,
+    settle_timer: TimerToken,
+    content_alignment: UnitPoint,
+    content_origin: Point,
+}
+
+impl<T, W: Widget<T>> BindableScroll<T, W> {
+    /// Scroll child on both axes
+    pub fn new(child: W) -> Self {
+        BindableScroll {
+            child: WidgetPod::new(child),
+            scroll_component: ScrollComponent::new(),
+            port: Viewport::default(),
+            direction: None,
+            snap_horizontal: SnapPoints::None,
+            snap_vertical: SnapPoints::None,
+            settle_timer: TimerToken::INVALID,
+            content_alignment: UnitPoint::TOP_LEFT,
+            content_origin: Point::ORIGIN,
+        }
+    }
+
+    /// Only scroll vertically, constraining the width of the child to that of the scroll
+    pub fn vertical(mut self) -> Self {
+        self.direction = Some(Axis::Vertical);
+        self
+    }
+
+    /// Only scroll horizontally, constraining the height of the child to that of the scroll
+    pub fn horizontal(mut self) -> Self {
+        self.direction = Some(Axis::Horizontal);
+        self
+    }
+
+    /// The scroll offset on an axis
+    pub fn offset_for_axis(&self, axis: Axis) -> f64 {
+        axis.major_pos(self.viewport_rect().origin())
+    }
+
+    /// Scroll to position on an axis, limited to the scrollable distance.
+    /// Returns whether the offset changed. Request a layout afterwards to show the new offset.
+    pub fn scroll_to_on_axis(&mut self, axis: Axis, position: f64) -> bool {
+        let mut origin = self.viewport_rect().origin();
+        match axis {
+            Axis::Horizontal => origin.x = position,
+            Axis::Vertical => origin.y = position,
+        }
+        self.port.pan_to(origin)
+    }
+
+    /// The furthest the content can be scrolled on an axis,
+    /// ie the content extent less the viewport extent.
+    pub fn limit_for_axis(&self, axis: Axis) -> f64 {
+        (axis.major(self.port.content_size) - axis.major(self.viewport_rect().size())).max(0.)
+    }
+
+    /// The snap points on an axis
+    pub fn snap_points(&self, axis: Axis) -> &SnapPoints {
+        match axis {
+            Axis::Horizontal => &self.snap_horizontal,
+            Axis::Vertical => &self.snap_vertical,
+        }
+    }
+
+    /// Set the snap points on an axis. The offset settles on them the next time it is snapped.
+    pub fn set_snap_points(&mut self, axis: Axis, snap_points: SnapPoints) {
+        match axis {
+            Axis::Horizontal => self.snap_horizontal = snap_points,
+            Axis::Vertical => self.snap_vertical = snap_points,
+        }
+    }
+
+    /// Scroll to the snap point nearest position on an axis.
+    /// Returns whether the offset changed.
+    pub fn snap_to_on_axis(&mut self, axis: Axis, position: f64) -> bool {
+        let target = self
+            .snap_points(axis)
+            .nearest(position, self.limit_for_axis(axis));
+        self.scroll_to_on_axis(axis, target)
+    }
+
+    /// Move the offset on both axes to the nearest snap points.
+    /// Returns whether the offset changed.
+    pub fn snap(&mut self) -> bool {
+        let horizontal =
+            self.snap_to_on_axis(Axis::Horizontal, self.offset_for_axis(Axis::Horizontal));
+        let vertical = self.snap_to_on_axis(Axis::Vertical, self.offset_for_axis(Axis::Vertical));
+        horizontal || vertical
+    }
+
+    fn snaps(&self) -> bool {
+        self.snap_horizontal != SnapPoints::None || self.snap_vertical != SnapPoints::None
+    }
+
+    /// Where content smaller than the viewport sits within it, on the axes where it is smaller
+    pub fn content_alignment(&self) -> UnitPoint {
+        self.content_alignment
+    }
+
+    /// Set where content smaller than the viewport sits within it, eg UnitPoint::CENTER.
+    /// Content starts at the top left by default.
+    pub fn set_content_alignment(&mut self, alignment: UnitPoint) {
+        self.content_alignment = alignment;
+    }
+
+    /// The visible part of the content, in content coordinates
+    pub fn viewport_rect(&self) -> Rect {
+        compat::view_rect(&self.port)
+    }
+}
+
+// On druid master a WidgetPod sends events to and paints its child where the child is,
+// so the child is moved to show the scrolled part of it.
+#[cfg(feature = "druid-master")]
+impl<T: Data, W: Widget<T>> BindableScroll<T, W> {
+    fn child_origin(&self) -> Point {
+        self.content_origin - self.viewport_rect().origin().to_vec2()
+    }
+
+    fn place_scrolled_child(&mut self, ctx: &mut EventCtx) {
+        let origin = self.child_origin();
+        self.child.set_origin(ctx, origin)
+    }
+
+    fn child_event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.child.event(ctx, event, data, env)
+    }
+
+    fn paint_child(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env)
+    }
+}
+
+// On druid 0.7 the child stays where it is laid out, and its events and painting are offset by the scroll instead.
+#[cfg(not(feature = "druid-master"))]
+impl<T: Data, W: Widget<T>> BindableScroll<T, W> {
+    fn child_origin(&self) -> Point {
+        self.content_origin
+    }
+
+    fn place_scrolled_child(&mut self, _ctx: &mut EventCtx) {}
+
+    fn child_event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let viewport = ctx.size().to_rect();
+        let force_event = self.child.is_hot() || self.child.is_active();
+        let offset = self.viewport_rect().origin().to_vec2();
+        if let Some(child_event) = event.transform_scroll(offset, viewport, force_event) {
+            self.child.event(ctx, &child_event, data, env);
+        }
+    }
+
+    fn paint_child(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let origin = self.content_origin.to_vec2();
+        let offset = self.viewport_rect().origin().to_vec2();
+        ctx.transform(Affine::translate(origin - offset));
+        let visible = ctx.region().bounding_box() + offset - origin;
+        ctx.with_child_ctx(visible, |ctx| self.child.paint_raw(ctx, data, env));
+    }
+}
+
+impl<T, W> crate::BindableAccess for BindableScroll<T, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for BindableScroll<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Timer(token) = event {
+            if *token == self.settle_timer {
+                self.settle_timer = TimerToken::INVALID;
+                if self.scroll_component.are_bars_held() {
+                    // Still dragging a scrollbar, so settle once it is let go
+                    self.settle_timer = ctx.request_timer(SETTLE_DELAY);
+                } else if self.snap() {
+                    self.place_scrolled_child(ctx);
+                    ctx.request_paint();
+                }
+                ctx.set_handled();
+                return;
+            }
+        }
+        let offset = self.viewport_rect().origin();
+        self.scroll_component.event(&mut self.port, ctx, event, env);
+        if self.viewport_rect().origin() != offset {
+            // Dragging a scrollbar moved the content
+            self.place_scrolled_child(ctx);
+        }
+        if !ctx.is_handled() {
+            self.child_event(ctx, event, data, env);
+        }
+        // Scroll after the child has had the event, so that scrolling inside the child wins
+        let before_wheel = self.viewport_rect().origin();
+        self.scroll_component
+            .handle_scroll(&mut self.port, ctx, event, env);
+        if self.viewport_rect().origin() != before_wheel {
+            self.place_scrolled_child(ctx);
+        }
+        // Settle once the user has stopped scrolling for a moment
+        if self.snaps() && self.viewport_rect().origin() != offset {
+            self.settle_timer = ctx.request_timer(SETTLE_DELAY);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.scroll_component.lifecycle(ctx, event, env);
+        self.child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let max = match self.direction {
+            Some(Axis::Vertical) => Size::new(bc.max().width, f64::INFINITY),
+            Some(Axis::Horizontal) => Size::new(f64::INFINITY, bc.max().height),
+            None => Size::new(f64::INFINITY, f64::INFINITY),
+        };
+        let child_size = self
+            .child
+            .layout(ctx, &BoxConstraints::new(Size::ZERO, max), data, env);
+        let viewport_size = bc.constrain(child_size);
+        self.port.content_size = child_size;
+        let view_origin = self.viewport_rect().origin();
+        compat::set_view_rect(
+            &mut self.port,
+            Rect::from_origin_size(view_origin, viewport_size),
+        );
+        // Keep the offset within the new limits
+        self.port.pan_to(view_origin);
+
+        // Any space left over around content smaller than the viewport goes by the alignment
+        let free = viewport_size - child_size;
+        self.content_origin = self.content_alignment.resolve(Rect::new(
+            0.,
+            0.,
+            free.width.max(0.),
+            free.height.max(0.),
+        ));
+        let child_origin = self.child_origin();
+        compat::set_origin(&mut self.child, ctx, data, env, child_origin);
+        viewport_size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let viewport = ctx.size().to_rect();
+        ctx.with_save(|ctx| {
+            ctx.clip(viewport);
+            self.paint_child(ctx, data, env);
+        });
+        self.scroll_component.draw_bars(ctx, &self.port, env);
+    }
+}
+
+/// A bindable property for the scroll offset of a BindableScroll on an axis.
+/// An offset written from the data goes to the nearest snap point, if the axis has any.
+pub struct BindableScrollOffsetProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> BindableScrollOffsetProperty<T, W> {
+    /// Create a scroll offset property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        BindableScrollOffsetProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BindableScrollOffsetProperty<T, W> {
+    type Controlled = BindableScroll<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        if controlled.snap_to_on_axis(self.direction, *field_val) {
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.offset_for_axis(self.direction).same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.offset_for_axis(self.direction)
+    }
+}
+
+/// A read-only property exposing the furthest a BindableScroll can be scrolled on an axis
+pub struct BindableScrollLimitProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> BindableScrollLimitProperty<T, W> {
+    /// Create a scroll limit property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        BindableScrollLimitProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BindableScrollLimitProperty<T, W> {
+    type Controlled = BindableScroll<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.limit_for_axis(self.direction).same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.limit_for_axis(self.direction)
+    }
+}
+
+/// A read-only property exposing the visible part of a BindableScroll's content, in content coordinates
+pub struct BindableScrollViewportProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for BindableScrollViewportProperty<T, W> {
+    fn default() -> Self {
+        BindableScrollViewportProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BindableScrollViewportProperty<T, W> {
+    type Controlled = BindableScroll<T, W>;
+    type Value = Rect;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.viewport_rect() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.viewport_rect()
+    }
+}
+
+/// A write-only property for the snap points of a BindableScroll on an axis, for carousels and paged views.
+/// Scrolling by the user settles on the nearest snap point once it pauses,
+/// and offsets written through BindableScrollOffsetProperty go straight to one.
+pub struct BindableScrollSnapProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> BindableScrollSnapProperty<T, W> {
+    /// Create a snap points property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        BindableScrollSnapProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BindableScrollSnapProperty<T, W> {
+    type Controlled = BindableScroll<T, W>;
+    type Value = SnapPoints;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_snap_points(self.direction, field_val.clone());
+        let offset = controlled.offset_for_axis(self.direction);
+        if controlled.snap_to_on_axis(self.direction, offset) {
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
+
+/// A write-only property for where content smaller than a BindableScroll's viewport sits within it,
+/// eg UnitPoint::CENTER to centre a small document. Each axis is aligned only while the content is smaller on it.
+pub struct BindableScrollAlignmentProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for BindableScrollAlignmentProperty<T, W> {
+    fn default() -> Self {
+        BindableScrollAlignmentProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for BindableScrollAlignmentProperty<T, W> {
+    type Controlled = BindableScroll<T, W>;
+    type Value = UnitPoint;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_content_alignment(*field_val);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(points: &[f64]) -> SnapPoints {
+        SnapPoints::Points(Arc::new(points.to_vec()))
+    }
+
+    #[test]
+    fn positions_snap_to_the_nearest_point_within_the_limit() {
+        assert_eq!(SnapPoints::Interval(100.).nearest(130., 1000.), 100.);
+        assert_eq!(SnapPoints::Interval(100.).nearest(170., 1000.), 200.);
+        assert_eq!(SnapPoints::Interval(100.).nearest(980., 950.), 950.);
+        assert_eq!(points(&[0., 250., 600.]).nearest(400., 1000.), 250.);
+        assert_eq!(points(&[0., 250., 1200.]).nearest(900., 1000.), 1000.);
+    }
+
+    #[test]
+    fn without_points_positions_stay_where_they_are() {
+        assert_eq!(SnapPoints::None.nearest(123., 1000.), 123.);
+        assert_eq!(points(&[]).nearest(123., 1000.), 123.);
+        assert_eq!(SnapPoints::Interval(0.).nearest(123., 1000.), 123.);
+        assert_eq!(SnapPoints::Interval(-10.).nearest(123., 1000.), 123.);
+    }
+
+    #[test]
+    fn ties_go_to_the_lower_multiple_or_the_earlier_point() {
+        assert_eq!(SnapPoints::Interval(100.).nearest(150., 1000.), 100.);
+        assert_eq!(points(&[300., 100.]).nearest(200., 1000.), 300.);
+        assert_eq!(points(&[100., 300.]).nearest(200., 1000.), 100.);
+    }
+
+    #[test]
+    fn points_that_arent_finite_are_skipped() {
+        assert_eq!(points(&[f64::NAN, 300.]).nearest(100., 1000.), 300.);
+        assert_eq!(points(&[f64::INFINITY, 300.]).nearest(900., 1000.), 300.);
+        assert_eq!(points(&[f64::NAN]).nearest(100., 1000.), 100.);
+        assert_eq!(SnapPoints::Interval(f64::NAN).nearest(100., 1000.), 100.);
+        assert_eq!(
+            SnapPoints::Interval(f64::INFINITY).nearest(100., 1000.),
+            100.
+        );
+        assert!(points(&[0., 300.]).nearest(f64::NAN, 1000.).is_nan());
+        assert!(SnapPoints::Interval(100.).nearest(f64::NAN, 1000.).is_nan());
+    }
+}
@@ -0,0 +1,427 @@
+use crate::binding::BindableProperty;
+use crate::change::TextDelta;
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::widget::TextBox;
+use druid::{Data, Point, WidgetPod};
+use std::marker::PhantomData;
+#[cfg(feature = "druid-master")]
+use std::ops::Range;
+
+/// A TextBox that edits a String which it holds itself, rather than its data.
+/// Bind TextBoxTextProperty to it to edit a field that isn't the data of the surrounding widgets,
+/// eg inside a Scope, or a field reached through a lens that doesn't lead to a String.
+pub struct BindableTextBox<T> {
+    text: String,
+    text_box: WidgetPod<String, TextBox<String>>,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> BindableTextBox<T> {
+    /// An empty single line TextBox
+    pub fn new() -> Self {
+        Self::from_text_box(TextBox::new())
+    }
+
+    /// An empty multi line TextBox
+    pub fn multiline() -> Self {
+        Self::from_text_box(TextBox::multiline())
+    }
+
+    /// Hold the text for an already configured TextBox
+    pub fn from_text_box(text_box: TextBox<String>) -> Self {
+        BindableTextBox {
+            text: String::new(),
+            text_box: WidgetPod::new(text_box),
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The text currently in the box
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text in the box. It is shown at the next update.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into()
+    }
+
+    /// The byte range of the text an input method is composing, eg the preedit text of a CJK input method,
+    /// if a composition is in progress
+    #[cfg(feature = "druid-master")]
+    pub fn composition_range(&self) -> Option<Range<usize>> {
+        let component = self.text_box.widget().text();
+        // The input method holds the session while it edits it, but never across widget methods
+        if component.can_read() {
+            component.borrow().composition_range()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Default for BindableTextBox<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> crate::BindableAccess for BindableTextBox<T> {
+    bindable_self_body!();
+}
+
+impl<T: Data> Widget<T> for BindableTextBox<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut T, env: &Env) {
+        self.text_box.event(ctx, event, &mut self.text, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, env: &Env) {
+        self.text_box.lifecycle(ctx, event, &self.text, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, env: &Env) {
+        self.text_box.update(ctx, &self.text, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, env: &Env) -> Size {
+        let size = self.text_box.layout(ctx, bc, &self.text, env);
+        compat::set_origin(&mut self.text_box, ctx, &self.text, env, Point::ORIGIN);
+        ctx.set_baseline_offset(self.text_box.baseline_offset());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, env: &Env) {
+        self.text_box.paint(ctx, &self.text, env)
+    }
+}
+
+/// A bindable property for the text of a BindableTextBox
+pub struct TextBoxTextProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> Default for TextBoxTextProperty<T> {
+    fn default() -> Self {
+        TextBoxTextProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T: Data> BindableProperty for TextBoxTextProperty<T> {
+    type Controlled = BindableTextBox<T>;
+    type Value = String;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        if controlled.text() != field_val {
+            controlled.set_text(field_val.clone());
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.text() != field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.text().to_string()
+    }
+}
+
+/// A bindable property for the text of a BindableTextBox that applies only the edited ranges to the field,
+/// rather than replacing all of it, for large texts.
+///
+/// The pending change is always the one edit from the field to the widget's text, so edits made before
+/// it is applied are conflated into it, and it is dropped when the field is written to the widget.
+/// If the field has moved on from the text the change was made against anyway,
+/// the whole text is copied into it instead.
+pub struct TextBoxTextDeltaProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> Default for TextBoxTextDeltaProperty<T> {
+    fn default() -> Self {
+        TextBoxTextDeltaProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T: Data> BindableProperty for TextBoxTextDeltaProperty<T> {
+    type Controlled = BindableTextBox<T>;
+    type Value = String;
+    type Change = TextDelta;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        // Any pending change was made against the text replaced here,
+        // and is dropped when the host next checks for changes, as the widget now agrees with the field
+        if controlled.text() != field_val {
+            controlled.set_text(field_val.clone());
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        // The field doesn't reflect a pending change yet, so the edit from it covers the pending one too
+        let delta = TextDelta::between(field_val, controlled.text());
+        *change = if delta.is_empty() { None } else { Some(delta) };
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        _env: &Env,
+    ) {
+        if !change.apply_to(field) || field != controlled.text() {
+            field.clear();
+            field.push_str(controlled.text())
+        }
+    }
+}
+
+/// A read-only property exposing the byte range of a BindableTextBox's text that an input method is composing,
+/// eg for editor chrome marking the composition inline. The field is None when nothing is being composed.
+///
+/// Druid doesn't let widgets outside a TextBox reach its input handler, so the region the platform
+/// treats as the text input's hot area can't be written through a binding.
+#[cfg(feature = "druid-master")]
+pub struct TextBoxCompositionProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+#[cfg(feature = "druid-master")]
+impl<T> Default for TextBoxCompositionProperty<T> {
+    fn default() -> Self {
+        TextBoxCompositionProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "druid-master")]
+impl<T: Data> BindableProperty for TextBoxCompositionProperty<T> {
+    type Controlled = BindableTextBox<T>;
+    type Value = Option<Range<usize>>;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.composition_range() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.composition_range()
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::binding::{BindingHost, LensPropBinding, SYNC_FROM_WIDGET};
+    use crate::change::TextEdit;
+    use crate::testing::{detect_change, PropertyHarness};
+    use druid::tests::harness::Harness;
+    use druid::widget::{Controller, ControllerHost};
+    use druid::{lens, Selector, WidgetId};
+    use std::time::Duration;
+
+    /// Set the text in a BindableTextBox, as typing into it would
+    const TYPE: Selector<String> = Selector::new("druid-bindings.test.type");
+    /// Set the data, as app logic would
+    const WRITE: Selector<String> = Selector::new("druid-bindings.test.write");
+
+    struct Typist;
+
+    impl Controller<String, BindableTextBox<String>> for Typist {
+        fn event(
+            &mut self,
+            child: &mut BindableTextBox<String>,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut String,
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(c) if c.is(TYPE) => child.set_text(c.get_unchecked(TYPE).clone()),
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    struct Writer;
+
+    impl<W: Widget<String>> Controller<String, W> for Writer {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut String,
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(c) if c.is(WRITE) => *data = c.get_unchecked(WRITE).clone(),
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    #[test]
+    fn deltas_are_dropped_when_the_field_is_written() {
+        let id = WidgetId::next();
+        let mut text_box = BindableTextBox::new();
+        text_box.set_text("hello world");
+        let host = BindingHost::<String, String, _, _, _>::new(
+            ControllerHost::new(text_box, Typist),
+            LensPropBinding::new(lens::Identity, TextBoxTextDeltaProperty::default()),
+        )
+        .deferred(Duration::from_secs(60))
+        .with_id(id);
+        let root = ControllerHost::new(host, Writer);
+        Harness::create_simple("hello world".to_string(), root, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.submit_command(TYPE.with("hello there world".into()).to(id));
+            // The deferred edit is still pending when the app replaces the text
+            harness.submit_command(WRITE.with("goodbye world".into()));
+            harness.submit_command(SYNC_FROM_WIDGET.to(id));
+            assert_eq!(harness.data(), "goodbye world");
+        });
+    }
+
+    #[test]
+    fn text_boxes_hold_their_own_text() {
+        let mut text_box = BindableTextBox::<()>::default();
+        assert_eq!(text_box.text(), "");
+        text_box.set_text("hello");
+        assert_eq!(text_box.text(), "hello");
+        assert_eq!(text_box.composition_range(), None);
+    }
+
+    #[test]
+    fn the_text_is_written_and_read_back() {
+        PropertyHarness::run(
+            TextBoxTextProperty::default(),
+            BindableTextBox::<()>::new(),
+            "hello".to_string(),
+            |harness| {
+                assert_eq!(harness.controlled().text(), "hello");
+                assert!(!harness.read_back());
+                harness.write("world".into());
+                assert_eq!(harness.controlled().text(), "world");
+                harness.controlled_mut().set_text("typed");
+                assert!(harness.read_back());
+                assert_eq!(harness.field(), "typed");
+                assert!(!harness.read_back());
+            },
+        );
+    }
+
+    #[test]
+    fn the_text_is_written_and_read_back_as_deltas() {
+        PropertyHarness::run(
+            TextBoxTextDeltaProperty::default(),
+            BindableTextBox::<()>::new(),
+            "hello".to_string(),
+            |harness| {
+                assert_eq!(harness.controlled().text(), "hello");
+                assert!(!harness.read_back());
+                harness.write("hello world".into());
+                assert_eq!(harness.controlled().text(), "hello world");
+                harness.controlled_mut().set_text("hello big world");
+                assert!(harness.read_back());
+                assert_eq!(harness.field(), "hello big world");
+                assert!(!harness.read_back());
+            },
+        );
+    }
+
+    #[test]
+    fn edits_before_an_apply_make_one_delta() {
+        PropertyHarness::run(
+            TextBoxTextDeltaProperty::default(),
+            BindableTextBox::<()>::new(),
+            "hello world".to_string(),
+            |harness| {
+                harness.controlled_mut().set_text("hello big world");
+                harness.controlled_mut().set_text("hello big wide world");
+                let change = detect_change(
+                    &TextBoxTextDeltaProperty::default(),
+                    &*harness.controlled(),
+                    harness.field(),
+                    &Env::empty(),
+                );
+                assert_eq!(
+                    change.map(|delta| delta.edits),
+                    Some(vec![TextEdit {
+                        range: 6..6,
+                        text: "big wide ".into()
+                    }])
+                );
+                assert!(harness.read_back());
+                assert_eq!(harness.field(), "hello big wide world");
+            },
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::{Affine, Insets, MouseEvent, Point, Vec2, WidgetPod};
+
+/// Shows its child rotated, scaled and moved about the child's centre, without changing its layout,
+/// eg for a chevron that turns as a section opens, or a needle on a gauge.
+/// Mouse events are mapped back through the transform, so the child can still be used.
+///
+/// Bind TransformRotationProperty, TransformScaleProperty and TransformTranslationProperty to drive it from data.
+/// Changes are laid out again as well as repainted, to keep the painted area covering the transformed child.
+pub struct BindableTransform<T, W> {
+    child: WidgetPod<T, W>,
+    rotation: f64,
+    scale: f64,
+    translation: Vec2,
+}
+
+impl<T, W: Widget<T>> BindableTransform<T, W> {
+    /// Show child untransformed
+    pub fn new(child: W) -> Self {
+        BindableTransform {
+            child: WidgetPod::new(child),
+            rotation: 0.,
+            scale: 1.,
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// The rotation clockwise, in radians
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    /// Set the rotation clockwise, in radians
+    pub fn set_rotation(&mut self, rotation: f64) {
+        self.rotation = rotation
+    }
+
+    /// The scale factor
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Set the scale factor
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = scale
+    }
+
+    /// How far the child is moved, after rotating and scaling it
+    pub fn translation(&self) -> Vec2 {
+        self.translation
+    }
+
+    /// Set how far the child is moved, after rotating and scaling it
+    pub fn set_translation(&mut self, translation: Vec2) {
+        self.translation = translation
+    }
+
+    /// Maps points in the child to points in this widget
+    fn transform(&self, size: Size) -> Affine {
+        let centre = size.to_vec2() / 2.;
+        Affine::translate(self.translation + centre)
+            * Affine::rotate(self.rotation)
+            * Affine::scale(self.scale)
+            * Affine::translate(-centre)
+    }
+
+    fn to_child(&self, size: Size, mouse: &MouseEvent) -> MouseEvent {
+        let mut mouse = mouse.clone();
+        mouse.pos = self.transform(size).inverse() * mouse.pos;
+        mouse
+    }
+}
+
+impl<T, W> crate::BindableAccess for BindableTransform<T, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for BindableTransform<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let size = ctx.size();
+        let child_event = match event {
+            Event::MouseDown(mouse) => Event::MouseDown(self.to_child(size, mouse)),
+            Event::MouseUp(mouse) => Event::MouseUp(self.to_child(size, mouse)),
+            Event::MouseMove(mouse) => Event::MouseMove(self.to_child(size, mouse)),
+            Event::Wheel(mouse) => Event::Wheel(self.to_child(size, mouse)),
+            _ => event.clone(),
+        };
+        self.child.event(ctx, &child_event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.child.layout(ctx, bc, data, env);
+        compat::set_origin(&mut self.child, ctx, data, env, Point::ORIGIN);
+        // Paint wherever the transformed child ends up
+        let painted = self.transform(size).transform_rect_bbox(size.to_rect());
+        ctx.set_paint_insets(Insets::new(
+            (-painted.x0).max(0.),
+            (-painted.y0).max(0.),
+            (painted.x1 - size.width).max(0.),
+            (painted.y1 - size.height).max(0.),
+        ));
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let transform = self.transform(ctx.size());
+        ctx.with_save(|ctx| {
+            ctx.transform(transform);
+            self.child.paint_raw(ctx, data, env)
+        })
+    }
+}
+
+bindable_props! {
+    impl<T: Data, W: Widget<T>> for BindableTransform<T, W> {
+        /// A write-only property for the clockwise rotation of a BindableTransform, in radians
+        pub TransformRotationProperty: f64 => set_rotation, request_layout;
+        /// A write-only property for the scale factor of a BindableTransform
+        pub TransformScaleProperty: f64 => set_scale, request_layout;
+        /// A write-only property for how far a BindableTransform moves its child
+        pub TransformTranslationProperty: Vec2 => set_translation, request_layout;
+    }
+}
@@ -1,8 +1,22 @@
+use druid::lens::{LensExt, Then};
 use druid::{
-    BoxConstraints, Data, Env, Event, EventCtx, LayoutCtx, Lens, LifeCycle, LifeCycleCtx, PaintCtx,
-    Selector, Size, UpdateCtx, Widget
+    BoxConstraints, Data, Env, Event, EventCtx, Key, LayoutCtx, Lens, LifeCycle, LifeCycleCtx,
+    PaintCtx, Selector, Size, TimerToken, UpdateCtx, Widget, WidgetId,
 };
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use std::time::Duration;
+use crate::adopt::AdoptIfUnsetBinding;
+use crate::diagnostics::{short_type_name, StuckAction, StuckDetector};
+use crate::dynamic_binding::{AttachBindings, BoxedBinding, ADD_BINDING, REMOVE_BINDING};
+use crate::exclusive::ExclusiveGroupBinding;
+use crate::fallible::{NoErrorLens, RejectionPolicy, TryBindableProperty, TryLensPropBinding};
+use crate::init_group::InitGroupMember;
+#[cfg(feature = "metrics")]
+use crate::metrics::{HostMetrics, SyncMetrics, DUMP_BINDING_METRICS};
+#[cfg(feature = "panic-guard")]
+use crate::panic_guard::{PanicGuardBinding, PanicMode};
 use crate::BindableAccess;
 
 
@@ -45,9 +59,73 @@ pub trait Binding<T, Controlled> {
         ctx: &mut EventCtx,
         env: &Env,
     );
+
+    /// The priority of this binding relative to the others it is combined with.
+    /// Combined bindings are applied in definition order in both directions, except that
+    /// a higher priority side of a combination is applied before a lower priority one.
+    /// This matters when several bindings write related state of one widget, eg a range before a value.
+    ///
+    /// Priorities only order the two sides of each pair, not every binding in a chain:
+    /// a nested pair is applied as a whole, at the priority of its highest priority member.
+    /// So in `a.and(b).and(c)`, c only goes before b if the pair of a and b is below it too.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Whether the value this binding writes to the controlled item depends on the Env,
+    /// eg a KeyOrValue<Color> resolved at write time.
+    fn env_dependent(&self) -> bool {
+        false
+    }
+
+    /// Reapply the parts of this binding that depend on the Env, after the Env has changed.
+    /// By default this reapplies the whole binding if it is env_dependent.
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        if self.env_dependent() {
+            self.apply_data_to_controlled(data, controlled, ctx, env)
+        }
+    }
+
+    /// Whether this binding writes the data to the controlled item when its host is added to the widget tree,
+    /// rather than waiting for the data to change.
+    fn wants_initial_data(&self) -> bool {
+        false
+    }
+
+    /// Write the data to the controlled item when the host has been added, if wants_initial_data.
+    fn apply_initial_data_to_controlled(
+        &self,
+        _data: &T,
+        _controlled: &mut Controlled,
+        _ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+    }
+
+    /// The bindings that can be attached and detached at runtime, if this is a DynamicBindings.
+    /// The host uses this to handle ADD_BINDING and REMOVE_BINDING. A pair of bindings passes on
+    /// the first of its members to have one, other wrappers don't pass it on.
+    fn attachable_mut(&mut self) -> Option<&mut dyn AttachBindings> {
+        None
+    }
+
+    /// A readable identity for this binding in logs and diagnostics, eg the lens and property it binds.
+    /// By default this is the binding's type name without module paths.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Allows a cons-list (or HList) of bindings to be built up, by treating a tuple of bindings as a binding.
+/// The first binding is applied before the second, unless the second has a higher priority.
+/// As the list nests to the left, priorities order the two sides of each pair rather than the whole list:
+/// a combination is applied as a whole, and has the priority of its highest priority member.
 impl<T, Controlled, Bind1: Binding<T, Controlled>, Bind2: Binding<T, Controlled>>
     Binding<T, Controlled> for (Bind1, Bind2)
 {
@@ -60,8 +138,13 @@ impl<T, Controlled, Bind1: Binding<T, Controlled>, Bind2: Binding<T, Controlled>
         ctx: &mut UpdateCtx,
         env: &Env,
     ) {
-        self.0.apply_data_to_controlled(data, controlled, ctx, env);
-        self.1.apply_data_to_controlled(data, controlled, ctx, env);
+        if self.1.priority() > self.0.priority() {
+            self.1.apply_data_to_controlled(data, controlled, ctx, env);
+            self.0.apply_data_to_controlled(data, controlled, ctx, env);
+        } else {
+            self.0.apply_data_to_controlled(data, controlled, ctx, env);
+            self.1.apply_data_to_controlled(data, controlled, ctx, env);
+        }
     }
 
     fn append_change_required(
@@ -90,20 +173,92 @@ impl<T, Controlled, Bind1: Binding<T, Controlled>, Bind2: Binding<T, Controlled>
         env: &Env,
     ) {
         let (change0, change1) = change;
+        let second_first = self.1.priority() > self.0.priority();
 
-        if let Some(change0) = change0 {
-            self.0
-                .apply_change_to_data(controlled, data, change0, ctx, env);
+        if second_first {
+            if let Some(change1) = change1 {
+                self.1
+                    .apply_change_to_data(controlled, data, change1, ctx, env);
+            }
+            if let Some(change0) = change0 {
+                self.0
+                    .apply_change_to_data(controlled, data, change0, ctx, env);
+            }
+        } else {
+            if let Some(change0) = change0 {
+                self.0
+                    .apply_change_to_data(controlled, data, change0, ctx, env);
+            }
+            if let Some(change1) = change1 {
+                self.1
+                    .apply_change_to_data(controlled, data, change1, ctx, env);
+            }
         }
+    }
+
+    fn priority(&self) -> i32 {
+        self.0.priority().max(self.1.priority())
+    }
 
-        if let Some(change1) = change1 {
+    fn env_dependent(&self) -> bool {
+        self.0.env_dependent() || self.1.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        if self.1.priority() > self.0.priority() {
+            self.1.apply_env_to_controlled(data, controlled, ctx, env);
+            self.0.apply_env_to_controlled(data, controlled, ctx, env);
+        } else {
+            self.0.apply_env_to_controlled(data, controlled, ctx, env);
+            self.1.apply_env_to_controlled(data, controlled, ctx, env);
+        }
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.0.wants_initial_data() || self.1.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        if self.1.priority() > self.0.priority() {
+            self.1
+                .apply_initial_data_to_controlled(data, controlled, ctx, env);
+            self.0
+                .apply_initial_data_to_controlled(data, controlled, ctx, env);
+        } else {
+            self.0
+                .apply_initial_data_to_controlled(data, controlled, ctx, env);
             self.1
-                .apply_change_to_data(controlled, data, change1, ctx, env);
+                .apply_initial_data_to_controlled(data, controlled, ctx, env);
         }
     }
+
+    fn attachable_mut(&mut self) -> Option<&mut dyn AttachBindings> {
+        if self.0.attachable_mut().is_some() {
+            self.0.attachable_mut()
+        } else {
+            self.1.attachable_mut()
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("({}, {})", self.0.describe(), self.1.describe())
+    }
 }
 
 /// One way binding wrappers
+#[derive(Debug)]
 pub struct DataToWidgetOnlyBinding<B>(pub B);
 
 impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
@@ -139,9 +294,123 @@ impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
         _env: &Env,
     ) {
     }
+
+    fn priority(&self) -> i32 {
+        self.0.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.0.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.0.apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.0.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.0
+            .apply_initial_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.forward()", self.0.describe())
+    }
+}
+
+/// This wraps another binding, making the data the single source of truth for it.
+/// The data is written to the controlled item as soon as the host is added, and on each change after that,
+/// and changes from the controlled item are never written back, even by properties capable of it.
+/// Unlike forward, which only filters, this means a mirror doesn't show the widget's own state until the data first changes.
+#[derive(Debug)]
+pub struct MirrorBinding<B>(pub B);
+
+impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled> for MirrorBinding<B> {
+    type Change = ();
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.0.apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn append_change_required(
+        &self,
+        _controlled: &Controlled,
+        _data: &T,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn apply_change_to_data(
+        &self,
+        _controlled: &Controlled,
+        _data: &mut T,
+        _change: Self::Change,
+        _ctx: &mut EventCtx,
+        _env: &Env,
+    ) {
+    }
+
+    fn priority(&self) -> i32 {
+        self.0.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.0.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.0.apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        true
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.0.apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.mirror()", self.0.describe())
+    }
 }
 
 /// This wraps another binding, and skips the flow from data to widget
+#[derive(Debug)]
 pub struct WidgetToDataOnlyBinding<B>(B);
 
 impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
@@ -179,6 +448,551 @@ impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled>
         self.0
             .apply_change_to_data(controlled, data, change, ctx, env);
     }
+
+    fn priority(&self) -> i32 {
+        self.0.priority()
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.back()", self.0.describe())
+    }
+}
+
+/// This wraps another binding, and logs each direction of synchronisation as it happens.
+/// Use it to find out why a binding isn't firing, or is firing more than expected.
+/// Each line gives the name, the wrapped binding's description, and the id of the host it runs in.
+/// Built with [`BindingExt::trace`] it logs the changes that pass through the binding.
+/// Built with [`BindingExt::trace_field`] it also logs the field of the data the binding syncs,
+/// as it was and as it is now, in both directions.
+#[derive(Debug)]
+pub struct TracingBinding<B, F = ()> {
+    name: String,
+    binding: B,
+    field: F,
+    /// The field as last traced, to show what each sync changed it from
+    last_field: RefCell<Option<String>>,
+    /// The id of the host, as of the last context this binding saw. Change detection gets no context.
+    host: Cell<Option<WidgetId>>,
+}
+
+/// How a TracingBinding shows the field of the data its binding syncs.
+/// This is implemented by () for bindings traced without their field, and by TracedField.
+pub trait TraceField<T> {
+    /// The field in data, formatted for the log, or None if it is not traced
+    fn show(&self, data: &T) -> Option<String>;
+}
+
+impl<T> TraceField<T> for () {
+    fn show(&self, _data: &T) -> Option<String> {
+        None
+    }
+}
+
+/// The field a lens points at, traced with its Debug impl
+pub struct TracedField<L, U> {
+    lens: L,
+    phantom_u: PhantomData<U>,
+}
+
+impl<L: Debug, U> Debug for TracedField<L, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracedField")
+            .field("lens", &self.lens)
+            .finish()
+    }
+}
+
+impl<T, U: Debug, L: Lens<T, U>> TraceField<T> for TracedField<L, U> {
+    fn show(&self, data: &T) -> Option<String> {
+        Some(self.lens.with(data, |value| format!("{:?}", value)))
+    }
+}
+
+impl<B> TracingBinding<B> {
+    /// Wrap a binding, identifying it in the log by name
+    pub fn new(name: impl Into<String>, binding: B) -> Self {
+        TracingBinding::with_field(name, binding, ())
+    }
+}
+
+impl<B, F> TracingBinding<B, F> {
+    /// Wrap a binding, identifying it in the log by name, and showing the data through field
+    pub fn with_field(name: impl Into<String>, binding: B, field: F) -> Self {
+        TracingBinding {
+            name: name.into(),
+            binding,
+            field,
+            last_field: RefCell::new(None),
+            host: Cell::new(None),
+        }
+    }
+
+    fn log_sync<T, Controlled>(&self, host: Option<WidgetId>, sync: std::fmt::Arguments)
+    where
+        B: Binding<T, Controlled>,
+    {
+        if host.is_some() {
+            self.host.set(host)
+        }
+        if !log::log_enabled!(log::Level::Debug) {
+            return;
+        }
+        match self.host.get() {
+            Some(id) => log::debug!(
+                "binding '{}' ({}) on {:?}: {}",
+                self.name,
+                self.binding.describe(),
+                id,
+                sync
+            ),
+            None => log::debug!(
+                "binding '{}' ({}) on a host not yet updated: {}",
+                self.name,
+                self.binding.describe(),
+                sync
+            ),
+        }
+    }
+
+    /// Describe how the field has moved since it was last traced, eg ", field 1 -> 2"
+    fn field_moved<T>(&self, from: Option<String>, data: &T) -> String
+    where
+        F: TraceField<T>,
+    {
+        if !log::log_enabled!(log::Level::Debug) {
+            return String::new();
+        }
+        let now = self.field.show(data);
+        let moved = match (&from, &now) {
+            (Some(from), Some(now)) => format!(", field {} -> {}", from, now),
+            (None, Some(now)) => format!(", field {}", now),
+            _ => String::new(),
+        };
+        *self.last_field.borrow_mut() = now;
+        moved
+    }
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>, F: TraceField<T>> Binding<T, Controlled>
+    for TracingBinding<B, F>
+where
+    B::Change: Debug,
+{
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let moved = self.field_moved(self.last_field.borrow_mut().take(), data);
+        self.log_sync(
+            Some(ctx.widget_id()),
+            format_args!("data -> widget{}", moved),
+        );
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let old = if log::log_enabled!(log::Level::Debug) {
+            change.as_ref().map(|change| format!("{:?}", change))
+        } else {
+            None
+        };
+        self.binding
+            .append_change_required(controlled, data, change, env);
+        match (old, change) {
+            (Some(old), Some(change)) => self.log_sync(
+                None,
+                format_args!("widget change appended, {} -> {:?}", old, change),
+            ),
+            (None, Some(change)) => {
+                self.log_sync(None, format_args!("widget change detected, {:?}", change))
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        let before = if log::log_enabled!(log::Level::Debug) {
+            Some(format!("{:?}", change))
+        } else {
+            None
+        };
+        let from = self.field.show(data);
+        self.binding
+            .apply_change_to_data(controlled, data, change, ctx, env);
+        if let Some(change) = before {
+            let moved = self.field_moved(from, data);
+            self.log_sync(
+                Some(ctx.widget_id()),
+                format_args!("widget -> data {}{}", change, moved),
+            );
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let moved = self.field_moved(self.last_field.borrow_mut().take(), data);
+        self.log_sync(
+            Some(ctx.widget_id()),
+            format_args!("initial data -> widget{}", moved),
+        );
+        self.binding
+            .apply_initial_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.trace({:?})", self.binding.describe(), self.name)
+    }
+}
+
+/// This wraps another binding, overriding its priority relative to the bindings it is combined with.
+#[derive(Debug)]
+pub struct PrioritisedBinding<B> {
+    priority: i32,
+    binding: B,
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled> for PrioritisedBinding<B> {
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.binding
+            .append_change_required(controlled, data, change, env);
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_change_to_data(controlled, data, change, ctx, env);
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_initial_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.with_priority({})",
+            self.binding.describe(),
+            self.priority
+        )
+    }
+}
+
+/// This wraps another binding, only picking up changes from the controlled item while a predicate
+/// on the data and the controlled item holds, eg to ignore scroll offsets while an animation runs.
+/// Changes found while the predicate fails are dropped, not held back. The data is still written as usual.
+pub struct FilteredBinding<B, F> {
+    binding: B,
+    predicate: F,
+}
+
+impl<B: Debug, F> Debug for FilteredBinding<B, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilteredBinding")
+            .field("binding", &self.binding)
+            .finish()
+    }
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>, F: Fn(&T, &Controlled) -> bool>
+    Binding<T, Controlled> for FilteredBinding<B, F>
+{
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        if (self.predicate)(data, controlled) {
+            self.binding
+                .append_change_required(controlled, data, change, env);
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_change_to_data(controlled, data, change, ctx, env);
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_initial_data_to_controlled(data, controlled, ctx, env);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.filter(..)", self.binding.describe())
+    }
+}
+
+/// This wraps another binding, switching it on and off with a bool in the Env,
+/// so that framework level flags (eg a debug key) can disable whole classes of bindings at once.
+/// A missing key counts as enabled. When the binding is switched back on the data is written to the
+/// controlled item again, to catch up with changes made while it was off.
+pub struct EnvEnabledBinding<B> {
+    key: Key<bool>,
+    binding: B,
+    was_enabled: Cell<bool>,
+}
+
+impl<B: Debug> Debug for EnvEnabledBinding<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnvEnabledBinding")
+            .field("binding", &self.binding)
+            .field("was_enabled", &self.was_enabled.get())
+            .finish()
+    }
+}
+
+impl<B> EnvEnabledBinding<B> {
+    fn enabled(&self, env: &Env) -> bool {
+        env.try_get(&self.key).unwrap_or(true)
+    }
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled> for EnvEnabledBinding<B> {
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let enabled = self.enabled(env);
+        if enabled {
+            self.binding
+                .apply_data_to_controlled(data, controlled, ctx, env);
+        }
+        self.was_enabled.set(enabled);
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        if self.enabled(env) {
+            self.binding
+                .append_change_required(controlled, data, change, env);
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        if self.enabled(env) {
+            self.binding
+                .apply_change_to_data(controlled, data, change, ctx, env);
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        // The key itself is in the Env
+        true
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let enabled = self.enabled(env);
+        if enabled && !self.was_enabled.get() {
+            self.binding
+                .apply_data_to_controlled(data, controlled, ctx, env);
+        } else if enabled {
+            self.binding
+                .apply_env_to_controlled(data, controlled, ctx, env);
+        }
+        self.was_enabled.set(enabled);
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let enabled = self.enabled(env);
+        if enabled {
+            self.binding
+                .apply_initial_data_to_controlled(data, controlled, ctx, env);
+        }
+        self.was_enabled.set(enabled);
+    }
+
+    fn describe(&self) -> String {
+        format!("{}.enabled_if_env(..)", self.binding.describe())
+    }
 }
 
 /// This binds two lenses that evaluate to the same type (PropValue) together.
@@ -218,6 +1032,18 @@ impl<T, Controlled, PropValue, LT: Lens<T, PropValue>, LC: Lens<Controlled, Prop
     }
 }
 
+/// Lenses are rarely Debug, so they are shown by their type names
+impl<T, Controlled, PropValue, LT: Lens<T, PropValue>, LC: Lens<Controlled, PropValue>> Debug
+    for LensBinding<T, Controlled, PropValue, LT, LC>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LensBinding")
+            .field("lens_from_data", &short_type_name::<LT>())
+            .field("lens_from_controlled", &short_type_name::<LC>())
+            .finish()
+    }
+}
+
 impl<T, Controlled, PropValue: Data, LT: Lens<T, PropValue>, LC: Lens<Controlled, PropValue>>
     Binding<T, Controlled> for LensBinding<T, Controlled, PropValue, LT, LC>
 {
@@ -268,8 +1094,46 @@ impl<T, Controlled, PropValue: Data, LT: Lens<T, PropValue>, LC: Lens<Controlled
     ) {
         self.lens_from_data.with_mut(data, |field| *field = change)
     }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.bind_lens({})",
+            short_type_name::<LT>(),
+            short_type_name::<LC>()
+        )
+    }
+}
+
+/// A lens from a value V to a value U that it converts to and from, for binding lenses of different types
+/// with bind_lens_converted. Each access converts the whole value, and writes convert it back.
+pub struct ConvertLens<ToV, ToU> {
+    to_v: ToV,
+    to_u: ToU,
+}
+
+impl<ToV, ToU> ConvertLens<ToV, ToU> {
+    /// Convert with to_u, and back with to_v
+    pub fn new(to_v: ToV, to_u: ToU) -> Self {
+        ConvertLens { to_v, to_u }
+    }
+}
+
+impl<V, U, ToV: Fn(&U) -> V, ToU: Fn(&V) -> U> Lens<V, U> for ConvertLens<ToV, ToU> {
+    fn with<R, F: FnOnce(&U) -> R>(&self, data: &V, f: F) -> R {
+        f(&(self.to_u)(data))
+    }
+
+    fn with_mut<R, F: FnOnce(&mut U) -> R>(&self, data: &mut V, f: F) -> R {
+        let mut converted = (self.to_u)(data);
+        let result = f(&mut converted);
+        *data = (self.to_v)(&converted);
+        result
+    }
 }
 
+/// A lens L onto a V, then converted to a U by a ConvertLens, as bound by bind_lens_converted
+pub type ConvertedLens<L, V, ToV, ToU> = Then<L, ConvertLens<ToV, ToU>, V>;
+
 /// This represents a property (usually on a widget) that can be bound
 pub trait BindableProperty {
     /// The controlled item - usually a widget.
@@ -313,6 +1177,18 @@ pub trait BindableProperty {
         change: Self::Change,
         env: &Env,
     );
+
+    /// Whether the value written to the controlled item depends on the Env.
+    /// Bindings to env dependent properties are rewritten when the Env changes, even if the data has not.
+    fn env_dependent(&self) -> bool {
+        false
+    }
+
+    /// A readable name for this property in logs and diagnostics.
+    /// By default this is the property's type name without module paths.
+    fn name(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// This binds a lens (LT) on some data (T) to a bindable property (PropC) on a widget (Controlled)
@@ -399,6 +1275,35 @@ impl<
                 .update_data_from_change(controlled, ctx, field, change, env)
         })
     }
+
+    fn env_dependent(&self) -> bool {
+        self.prop_from_controlled.env_dependent()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.bind({})",
+            short_type_name::<LT>(),
+            self.prop_from_controlled.name()
+        )
+    }
+}
+
+/// Lenses and properties are rarely Debug, so they are shown by their names
+impl<
+        T,
+        Controlled,
+        PropValue,
+        LT: Lens<T, PropValue>,
+        PropC: BindableProperty<Controlled = Controlled, Value = PropValue>,
+    > Debug for LensPropBinding<T, Controlled, PropValue, LT, PropC>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LensPropBinding")
+            .field("lens_from_data", &short_type_name::<LT>())
+            .field("prop_from_controlled", &self.prop_from_controlled.name())
+            .finish()
+    }
 }
 
 /// This trait provides combinators for building up bindings on lenses from data
@@ -410,6 +1315,18 @@ pub trait LensBindingExt<T, U>: Lens<T, U> + Sized {
         LensBinding::new(self, other)
     }
 
+    /// Bind this lens on data to a lens on a controlled item C of a different type V,
+    /// converting values with to_controlled on the way to C, and with to_data on the way back,
+    /// eg a usize field to an f64 on a widget
+    fn bind_lens_converted<C, V, L: Lens<C, V>, ToC: Fn(&U) -> V, ToD: Fn(&V) -> U>(
+        self,
+        other: L,
+        to_controlled: ToC,
+        to_data: ToD,
+    ) -> LensBinding<T, C, U, Self, ConvertedLens<L, V, ToC, ToD>> {
+        LensBinding::new(self, other.then(ConvertLens::new(to_controlled, to_data)))
+    }
+
     /// Bind this lens on data to a bindable property BP on a controlled item C (usually a widget)
     fn bind<BP: BindableProperty<Value = U>>(
         self,
@@ -417,6 +1334,28 @@ pub trait LensBindingExt<T, U>: Lens<T, U> + Sized {
     ) -> LensPropBinding<T, BP::Controlled, U, Self, BP> {
         LensPropBinding::new(self, prop)
     }
+
+    /// Bind this lens on data, holding the key of the chosen member of a group, to a boolean property
+    /// that should be true only on the member with this key
+    fn bind_exclusive<BP: BindableProperty<Value = bool>>(
+        self,
+        prop: BP,
+        key: U,
+    ) -> ExclusiveGroupBinding<T, U, Self, BP>
+    where
+        U: PartialEq,
+    {
+        ExclusiveGroupBinding::new(self, prop, key)
+    }
+
+    /// Bind this lens on data to a property BP that can reject values, handling rejections by policy
+    fn try_bind<BP: TryBindableProperty<Value = U>>(
+        self,
+        prop: BP,
+        policy: RejectionPolicy,
+    ) -> TryLensPropBinding<T, U, Self, BP, NoErrorLens> {
+        TryLensPropBinding::new(self, prop, policy)
+    }
 }
 
 impl<T, U, M: Lens<T, U> + Sized + 'static> LensBindingExt<T, U> for M {}
@@ -426,13 +1365,23 @@ pub trait WidgetBindingExt<T, U>: Widget<T> + Sized + BindableAccess
 where
     Self::Wrapped: Widget<U>,
 {
-    /// Bind properties in this widget using the binding B
+    /// Bind properties in this widget using the binding B.
+    /// Calling binding again on the result adds to the same host (see BindingHost::binding).
     fn binding<B: Binding<T, Self::Wrapped>>(
         self,
         binding: B,
     ) -> BindingHost<T, U, Self, Self::Wrapped, B> {
         BindingHost::new(self, binding)
     }
+
+    /// Bind properties in this widget using the binding B, in a host with the given id
+    fn binding_with_id<B: Binding<T, Self::Wrapped>>(
+        self,
+        binding: B,
+        id: WidgetId,
+    ) -> BindingHost<T, U, Self, Self::Wrapped, B> {
+        BindingHost::new(self, binding).with_id(id)
+    }
 }
 
 impl<T, U, W> WidgetBindingExt<T, U> for W
@@ -456,12 +1405,170 @@ pub trait BindingExt<T, Controlled>: Binding<T, Controlled> + Sized {
     fn forward(self) -> DataToWidgetOnlyBinding<Self> {
         DataToWidgetOnlyBinding(self)
     }
+    /// Make the data the single source of truth for this binding: it is written to the widget from the start,
+    /// and changes from the widget are ignored
+    fn mirror(self) -> MirrorBinding<Self> {
+        MirrorBinding(self)
+    }
+    /// Set the priority of this binding, to control the order it is applied in relative to the bindings
+    /// it is combined with. Higher priorities are applied first.
+    fn with_priority(self, priority: i32) -> PrioritisedBinding<Self> {
+        PrioritisedBinding {
+            priority,
+            binding: self,
+        }
+    }
+    /// Only take changes from the controlled item while predicate(data, controlled) holds
+    fn filter<F: Fn(&T, &Controlled) -> bool>(self, predicate: F) -> FilteredBinding<Self, F> {
+        FilteredBinding {
+            binding: self,
+            predicate,
+        }
+    }
+    /// Only run this binding while the bool at key in the Env is true
+    fn enabled_if_env(self, key: Key<bool>) -> EnvEnabledBinding<Self> {
+        EnvEnabledBinding {
+            key,
+            binding: self,
+            was_enabled: Cell::new(true),
+        }
+    }
+    /// Log every synchronisation this binding performs, identified by name
+    fn trace(self, name: impl Into<String>) -> TracingBinding<Self>
+    where
+        Self::Change: Debug,
+    {
+        TracingBinding::new(name, self)
+    }
+    /// Log every synchronisation this binding performs, identified by name,
+    /// along with the field lens points at before and after (usually the lens this binding syncs)
+    fn trace_field<U: Debug, L: Lens<T, U>>(
+        self,
+        name: impl Into<String>,
+        lens: L,
+    ) -> TracingBinding<Self, TracedField<L, U>>
+    where
+        Self::Change: Debug,
+    {
+        TracingBinding::with_field(
+            name,
+            self,
+            TracedField {
+                lens,
+                phantom_u: PhantomData,
+            },
+        )
+    }
+    /// Catch panics from this binding, logging them under name before resuming or dropping them according to mode
+    #[cfg(feature = "panic-guard")]
+    fn guard_panics(self, name: impl Into<String>, mode: PanicMode) -> PanicGuardBinding<Self> {
+        PanicGuardBinding::new(name, mode, self)
+    }
+    /// When the host is added, adopt the widget's state into the data if unset(data) holds, eg the field
+    /// is still at its default, and otherwise write the data to the widget. See field_is_default and field_is.
+    fn adopt_if_unset<F: Fn(&T) -> bool>(self, unset: F) -> AdoptIfUnsetBinding<Self, F> {
+        AdoptIfUnsetBinding::new(self, unset)
+    }
+    /// Box this binding up, erasing its type, eg to keep bindings of different types together
+    fn boxed(self) -> BoxedBinding<T, Controlled>
+    where
+        Self: 'static,
+        Self::Change: 'static,
+    {
+        BoxedBinding::new(self)
+    }
 }
 
 impl<T, Controlled, B: Binding<T, Controlled> + Sized> BindingExt<T, Controlled> for B {}
 
+/// Where a change from the controlled item came from, as far as the host can tell from when it was found.
+/// Undo systems, for example, can record only the changes made by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeOrigin {
+    /// Found after mouse, wheel, keyboard or paste input reached the widget
+    UserWidget,
+    /// The widget's response to the initial data written when the host was added
+    Init,
+    /// The widget's response to the data being written to it, or found in layout, lifecycle,
+    /// commands or timers, eg a value clamped by the widget echoing back
+    Programmatic,
+}
+
+impl ChangeOrigin {
+    /// The origin of changes found after event
+    fn of_event(event: &Event) -> Self {
+        match event {
+            Event::MouseDown(_)
+            | Event::MouseUp(_)
+            | Event::MouseMove(_)
+            | Event::Wheel(_)
+            | Event::KeyDown(_)
+            | Event::KeyUp(_)
+            | Event::Paste(_)
+            | Event::Zoom(_) => ChangeOrigin::UserWidget,
+            _ => ChangeOrigin::Programmatic,
+        }
+    }
+}
+
+/// Extra steps a BindingHost runs alongside its bindings, so that other widget libraries can integrate
+/// their widgets' own synchronisation with the host, rather than reimplementing it.
+/// Every method does nothing by default. Add hooks to a host with with_hooks.
+pub trait BindingHostHooks<T, Controlled> {
+    /// The host has been added to the widget tree
+    fn on_init(
+        &mut self,
+        _controlled: &mut Controlled,
+        _ctx: &mut LifeCycleCtx,
+        _data: &T,
+        _env: &Env,
+    ) {
+    }
+
+    /// Changes from the controlled item are about to be applied to the data
+    fn before_apply(
+        &mut self,
+        _controlled: &mut Controlled,
+        _ctx: &mut EventCtx,
+        _data: &T,
+        _env: &Env,
+    ) {
+    }
+
+    /// Changes from the controlled item have just been applied to the data
+    fn after_apply(
+        &mut self,
+        _controlled: &mut Controlled,
+        _ctx: &mut EventCtx,
+        _data: &T,
+        _env: &Env,
+    ) {
+    }
+
+    /// Changes from the controlled item have been applied to the data, having come from origin.
+    /// Changes found in several places before being applied take the user's as their origin if any were.
+    fn on_change(
+        &mut self,
+        _controlled: &mut Controlled,
+        _ctx: &mut EventCtx,
+        _data: &T,
+        _origin: ChangeOrigin,
+        _env: &Env,
+    ) {
+    }
+}
+
 /// A binding host wraps a BindableAccess, and offers bindings from the Data at this stage of the hierarchy
 /// to properties on that Bindable.
+///
+/// Data can only be changed in event. Changes from the widget are applied to the data before the
+/// contained widget sees an event, and again after it, so that the widget never handles an event
+/// against data missing its own earlier changes. Changes found in the other methods are queued, and the host
+/// submits itself an APPLY_BINDINGS command to get an event to apply them in. At most one of those
+/// is outstanding at a time, so however many changes are found, and however hosts are nested,
+/// each host costs at most one extra command round trip per batch of changes.
+/// Nor does a round trip lead to another: changes found while the data it applied is written back
+/// to the widget wait for the next event, so a widget and data that never agree can't keep commands going.
 pub struct BindingHost<
     T,
     U,
@@ -472,9 +1579,50 @@ pub struct BindingHost<
     contained: Contained,
     binding: B,
     pending_change: Option<B::Change>,
+    notification_name: Option<String>,
+    stuck_detector: Option<StuckDetector>,
+    initial_data_pending: bool,
+    defer: Option<Duration>,
+    defer_timer: TimerToken,
+    refresh: Option<Duration>,
+    refresh_timer: TimerToken,
+    pause_when_disabled: bool,
+    /// Whether the widget is disabled, as of the last event, update or DisabledChanged,
+    /// as the layout context can't tell
+    disabled: bool,
+    apply_submitted: bool,
+    /// Whether the last event applied changes an APPLY_BINDINGS brought in,
+    /// in which case the changes found as the data is written back don't submit another
+    in_round_trip: bool,
+    hooks: Vec<Box<dyn BindingHostHooks<T, Controlled>>>,
+    init_group: Option<InitGroupMember>,
+    resync_pending: bool,
+    pending_origin: Option<ChangeOrigin>,
+    id: Option<WidgetId>,
+    /// Whether the host is within the visible area of its ancestors, as of the last ViewContextChanged
+    visible: bool,
+    visibility: Option<Box<dyn VisibilityLens<T>>>,
+    #[cfg(feature = "metrics")]
+    metrics: HostMetrics,
     phantom_u: PhantomData<U>,
 }
 
+/// The lens to the bool field written with whether a host is visible, with its type erased
+trait VisibilityLens<T> {
+    fn visible(&self, data: &T) -> bool;
+    fn set_visible(&self, data: &mut T, visible: bool);
+}
+
+impl<T, L: Lens<T, bool>> VisibilityLens<T> for L {
+    fn visible(&self, data: &T) -> bool {
+        self.get(data)
+    }
+
+    fn set_visible(&self, data: &mut T, visible: bool) {
+        self.put(data, visible)
+    }
+}
+
 impl<
         T,
         U,
@@ -485,35 +1633,295 @@ impl<
 {
     /// Create a binding host from a Widget and a Binding
     pub fn new(contained: Contained, binding: B) -> Self {
+        #[cfg(feature = "metrics")]
+        let description = binding.describe();
         BindingHost {
             contained,
             binding,
             pending_change: None,
+            notification_name: None,
+            stuck_detector: None,
+            initial_data_pending: false,
+            defer: None,
+            defer_timer: TimerToken::INVALID,
+            refresh: None,
+            refresh_timer: TimerToken::INVALID,
+            pause_when_disabled: false,
+            disabled: false,
+            apply_submitted: false,
+            in_round_trip: false,
+            hooks: Vec::new(),
+            init_group: None,
+            resync_pending: false,
+            pending_origin: None,
+            id: None,
+            visible: true,
+            visibility: None,
+            #[cfg(feature = "metrics")]
+            metrics: HostMetrics::new(description),
+            phantom_u: Default::default(),
+        }
+    }
+
+    /// Add another binding to this host, rather than wrapping it in a second host, so all of the widget's
+    /// bindings share one host, one id and one round trip to apply their changes.
+    /// The new binding is combined after the existing ones, as with BindingExt::and.
+    /// A DynamicBindings host still takes ADD_BINDING and REMOVE_BINDING after another binding is added to it.
+    pub fn binding<B2: Binding<T, Controlled>>(
+        self,
+        binding: B2,
+    ) -> BindingHost<T, U, Contained, Controlled, (B, B2)> {
+        let binding = (self.binding, binding);
+        BindingHost {
+            #[cfg(feature = "metrics")]
+            metrics: self.metrics.describing(binding.describe()),
+            contained: self.contained,
+            binding,
+            pending_change: self.pending_change.map(|change| (Some(change), None)),
+            notification_name: self.notification_name,
+            stuck_detector: self.stuck_detector,
+            initial_data_pending: self.initial_data_pending,
+            defer: self.defer,
+            defer_timer: self.defer_timer,
+            refresh: self.refresh,
+            refresh_timer: self.refresh_timer,
+            pause_when_disabled: self.pause_when_disabled,
+            disabled: self.disabled,
+            apply_submitted: self.apply_submitted,
+            in_round_trip: self.in_round_trip,
+            hooks: self.hooks,
+            init_group: self.init_group,
+            resync_pending: self.resync_pending,
+            pending_origin: self.pending_origin,
+            id: self.id,
+            visible: self.visible,
+            visibility: self.visibility,
             phantom_u: Default::default(),
         }
     }
 
-    fn apply_pending_changes(&mut self, ctx: &mut EventCtx, data: &mut T, env: &Env) {
-        if let Some(change) = self.pending_change.take() {
+    /// Give the host an explicit id, so that application code can target it with commands.
+    /// Without one the host takes the id of the widget it contains, if that has one, eg an IdentityWrapper.
+    pub fn with_id(mut self, id: WidgetId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Watch for changes from the widget that never get applied to the data.
+    /// If the host asks for its changes to be applied threshold times without an event arriving,
+    /// it reports itself by its widget id and binding type, according to action.
+    pub fn diagnose_stuck(mut self, threshold: usize, action: StuckAction) -> Self {
+        self.stuck_detector = Some(StuckDetector::new(threshold, action));
+        self
+    }
+
+    /// Submit a BINDING_CHANGED notification carrying this name each time changes from the widget
+    /// are applied to the data, so that ancestor widgets and controllers can react to them.
+    pub fn notify_changes(mut self, name: impl Into<String>) -> Self {
+        self.notification_name = Some(name.into());
+        self
+    }
+
+    /// Hold changes from the widget back from the data until delay has passed since the first of them,
+    /// rather than applying them on the very next event. Changes made in between are coalesced by the binding,
+    /// so bindings with an expensive apply_change_to_data (eg rebuilding a large Vector) run once per burst.
+    pub fn deferred(mut self, delay: Duration) -> Self {
+        self.defer = Some(delay);
+        self
+    }
+
+    /// Write the data to the widget again every interval, even if it hasn't changed,
+    /// for bindings whose output depends on the time, eg a RelativeTimeProperty.
+    pub fn refresh_every(mut self, interval: Duration) -> Self {
+        self.refresh = Some(interval);
+        self
+    }
+
+    /// Stop syncing from the widget to the data while the widget is disabled.
+    /// Changes from a disabled widget are dropped rather than queued, though the data is still written to it.
+    /// Once it is enabled again changes are picked up as usual.
+    /// Widgets can only be disabled in druid master, so against druid 0.7 this has no effect.
+    pub fn pause_when_disabled(mut self) -> Self {
+        self.pause_when_disabled = true;
+        self
+    }
+
+    /// Join the named group of hosts that initialise the same data field from their widgets.
+    /// Only the hosts with the highest priority in the group write their widget's initial state into
+    /// the data, so the outcome doesn't depend on which host happens to initialise last.
+    /// Hosts with equal priority that both initialise are logged as a conflict.
+    /// Conflicts are only detected within a group: hosts that initialise the same field without joining
+    /// one are not, and the last of them to initialise wins.
+    /// Changes after the host has initialised, such as edits by the user, are applied by every host.
+    pub fn init_priority(mut self, group: impl Into<String>, priority: i32) -> Self {
+        self.init_group = Some(InitGroupMember::new(group.into(), priority));
+        self
+    }
+
+    /// Keep the bool field lens points at up to date with whether the host is on screen,
+    /// ie whether any of it is within the visible area of the Scrolls and ClipBoxes it is in,
+    /// eg to load the images of rows as they scroll into view. This is read-only: writing the field does nothing.
+    /// Hosts count as visible until druid first tells them where they are in the window,
+    /// which against druid 0.7 it never does.
+    pub fn bind_visibility(mut self, lens: impl Lens<T, bool> + 'static) -> Self {
+        self.visibility = Some(Box::new(lens));
+        self
+    }
+
+    /// Run hooks alongside the bindings. Hooks run in the order they were added.
+    pub fn with_hooks(mut self, hooks: impl BindingHostHooks<T, Controlled> + 'static) -> Self {
+        self.hooks.push(Box::new(hooks));
+        self
+    }
+
+    /// The synchronisations this host has performed so far
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> &SyncMetrics {
+        &self.metrics.counts
+    }
+
+    /// Apply the changes found so far to the data, returning whether there were any
+    fn apply_pending_changes(&mut self, ctx: &mut EventCtx, data: &mut T, env: &Env) -> bool {
+        let mut change = self.pending_change.take();
+        let origin = self
+            .pending_origin
+            .take()
+            .unwrap_or(ChangeOrigin::Programmatic);
+        let held_back = match &self.init_group {
+            Some(group) => change.is_some() && !group.should_apply(origin),
+            None => false,
+        };
+        if held_back {
+            // Another host initialises the data, so take the data instead of the widget's state
+            change = None;
+            self.resync_pending = true;
+            ctx.request_update();
+        }
+        let applied = change.is_some();
+        if let Some(change) = change {
+            for hooks in &mut self.hooks {
+                hooks.before_apply(self.contained.bindable_mut(), ctx, data, env);
+            }
             self.binding
-                .apply_change_to_data(self.contained.bindable(), data, change, ctx, env)
+                .apply_change_to_data(self.contained.bindable(), data, change, ctx, env);
+            for hooks in &mut self.hooks {
+                hooks.after_apply(self.contained.bindable_mut(), ctx, data, env);
+                hooks.on_change(self.contained.bindable_mut(), ctx, data, origin, env);
+            }
+            #[cfg(feature = "metrics")]
+            {
+                self.metrics.counts.widget_to_data += 1;
+            }
+            if let Some(name) = &self.notification_name {
+                ctx.submit_notification(BINDING_CHANGED.with(name.clone()));
+            }
+        }
+        if let Some(detector) = &mut self.stuck_detector {
+            detector.applied()
+        }
+        applied
+    }
+
+    /// Record that changes found outside of event need applying,
+    /// returning whether an APPLY_BINDINGS command should be submitted for them.
+    /// Only one is outstanding at a time: changes found before it arrives are applied along with it.
+    /// Changes found as a round trip's data is written back are kept, to be applied at the next event.
+    fn apply_needed(&mut self, id: WidgetId) -> bool {
+        if self.in_round_trip {
+            return false;
+        }
+        if let Some(detector) = &mut self.stuck_detector {
+            let binding = &self.binding;
+            detector.submitted(id, || binding.describe())
         }
+        !std::mem::replace(&mut self.apply_submitted, true)
     }
 
-    fn check_for_changes(&mut self, data: &T, env: &Env) -> bool {
+    fn check_for_changes(&mut self, data: &T, env: &Env, origin: ChangeOrigin) -> bool {
+        if self.disabled && self.pause_when_disabled {
+            self.pending_change = None;
+            self.pending_origin = None;
+            return false;
+        }
         self.binding.append_change_required(
             self.contained.bindable(),
             data,
             &mut self.pending_change,
             env,
         );
+        if self.pending_change.is_some()
+            && (self.pending_origin.is_none() || origin == ChangeOrigin::UserWidget)
+        {
+            self.pending_origin = Some(origin);
+        }
         self.pending_change.is_some()
     }
 }
 
+/// Access to the host's state machine, for tests of custom bindings and for tooling.
+/// Changes can be detected and inspected without a widget tree; applying them needs an EventCtx.
+#[cfg(feature = "testing")]
+impl<
+        T,
+        U,
+        Contained: BindableAccess<Wrapped = Controlled> + Widget<T>,
+        Controlled: Widget<U>,
+        B: Binding<T, Controlled>,
+    > BindingHost<T, U, Contained, Controlled, B>
+{
+    /// The change from the widget waiting to be applied to the data, if any
+    pub fn pending_change(&self) -> Option<&B::Change> {
+        self.pending_change.as_ref()
+    }
+
+    /// Where the pending change came from, if there is one
+    pub fn pending_origin(&self) -> Option<ChangeOrigin> {
+        self.pending_origin
+    }
+
+    /// The controlled item
+    pub fn controlled(&self) -> &Controlled {
+        self.contained.bindable()
+    }
+
+    /// The controlled item, mutably, eg to change its state as a user would before detect_changes
+    pub fn controlled_mut(&mut self) -> &mut Controlled {
+        self.contained.bindable_mut()
+    }
+
+    /// Look for changes from the controlled item against data, as the host does after each widget method,
+    /// recording them as coming from origin. Returns whether there are changes pending.
+    pub fn detect_changes(&mut self, data: &T, env: &Env, origin: ChangeOrigin) -> bool {
+        self.check_for_changes(data, env, origin)
+    }
+
+    /// Take the pending change without applying it
+    pub fn take_pending_change(&mut self) -> Option<B::Change> {
+        self.pending_origin = None;
+        self.pending_change.take()
+    }
+
+    /// Apply the pending change to data now, as the host does on receiving its apply command
+    pub fn apply_pending_changes_now(&mut self, ctx: &mut EventCtx, data: &mut T, env: &Env) {
+        self.apply_pending_changes(ctx, data, env);
+    }
+}
+
 /// This command is sent to self trigger event to run - which is where data can be modified.
 const APPLY_BINDINGS: Selector = Selector::new("druid-builtin.apply-bindings");
 
+/// This notification is submitted by a BindingHost configured with notify_changes
+/// whenever it applies changes from its widget to the data. The payload is the name given to the host.
+pub const BINDING_CHANGED: Selector<String> = Selector::new("druid-bindings.binding-changed");
+
+/// Sent to a BindingHost (or to a window, for all of the hosts within it) to write the data to its
+/// widget again, eg after the widget has been mutated out of band through a shared reference.
+pub const SYNC_FROM_DATA: Selector = Selector::new("druid-bindings.sync-from-data");
+
+/// Sent to a BindingHost (or to a window, for all of the hosts within it) to check its widget
+/// for changes and apply them to the data straight away, even if the host is deferred.
+pub const SYNC_FROM_WIDGET: Selector = Selector::new("druid-bindings.sync-from-widget");
+
 impl<
         T: Data,
         U,
@@ -523,49 +1931,211 @@ impl<
     > Widget<T> for BindingHost<T, U, Contained, Controlled, B>
 {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        #[cfg(feature = "druid-master")]
+        {
+            self.disabled = ctx.is_disabled();
+        }
+        let deferral_due = matches!(event, Event::Timer(token) if *token == self.defer_timer);
+        if deferral_due {
+            self.defer_timer = TimerToken::INVALID;
+        }
+
+        if let Some(visibility) = &self.visibility {
+            if visibility.visible(data) != self.visible {
+                visibility.set_visible(data, self.visible)
+            }
+        }
+
         // Changes that occurred in other methods
-        self.apply_pending_changes(ctx, data, env);
+        let applied =
+            (self.defer.is_none() || deferral_due) && self.apply_pending_changes(ctx, data, env);
+        self.in_round_trip = applied && matches!(event, Event::Command(c) if c.is(APPLY_BINDINGS));
 
         match event {
-            Event::Command(c) if c.is(APPLY_BINDINGS) => ctx.set_handled(), // We have handled this above
+            Event::Command(c) if c.is(APPLY_BINDINGS) => {
+                // We have handled this above
+                ctx.set_handled();
+                self.apply_submitted = false;
+                if self.initial_data_pending {
+                    ctx.request_update();
+                }
+            }
+            Event::Timer(_) if deferral_due => ctx.set_handled(),
+            Event::Timer(token) if *token == self.refresh_timer => {
+                ctx.set_handled();
+                // Data can only be written to the widget in update
+                self.resync_pending = true;
+                ctx.request_update();
+                if let Some(interval) = self.refresh {
+                    self.refresh_timer = ctx.request_timer(interval);
+                }
+            }
+            Event::Command(c) if c.is(ADD_BINDING) => match self.binding.attachable_mut() {
+                Some(attachable) => {
+                    ctx.set_handled();
+                    let added = c
+                        .get_unchecked(ADD_BINDING)
+                        .take()
+                        .map(|named| attachable.attach(named))
+                        .unwrap_or(false);
+                    if added {
+                        // Data can only be written to the widget in update
+                        self.resync_pending = true;
+                        ctx.request_update();
+                    }
+                }
+                None => self.contained.event(ctx, event, data, env),
+            },
+            Event::Command(c) if c.is(REMOVE_BINDING) => match self.binding.attachable_mut() {
+                Some(attachable) => {
+                    ctx.set_handled();
+                    attachable.detach(c.get_unchecked(REMOVE_BINDING).as_str());
+                }
+                None => self.contained.event(ctx, event, data, env),
+            },
+            Event::Command(c) if c.is(SYNC_FROM_DATA) => {
+                // Data can only be written to the widget in update
+                self.resync_pending = true;
+                ctx.request_update();
+                self.contained.event(ctx, event, data, env);
+            }
+            #[cfg(feature = "metrics")]
+            Event::Command(c) if c.is(DUMP_BINDING_METRICS) => {
+                self.metrics.log();
+                self.contained.event(ctx, event, data, env);
+            }
             _ => {
                 self.contained.event(ctx, event, data, env);
             }
         };
 
         // Changes that occurred just now
-        if self.check_for_changes(data, env) {
-            self.apply_pending_changes(ctx, data, env);
+        let forced = matches!(event, Event::Command(c) if c.is(SYNC_FROM_WIDGET));
+        let origin = ChangeOrigin::of_event(event);
+        if self.check_for_changes(data, env, origin) {
+            match self.defer {
+                Some(_) if forced => {
+                    self.apply_pending_changes(ctx, data, env);
+                }
+                Some(delay) => {
+                    if self.defer_timer == TimerToken::INVALID {
+                        self.defer_timer = ctx.request_timer(delay);
+                    }
+                }
+                None => {
+                    self.apply_pending_changes(ctx, data, env);
+                }
+            }
         }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
         self.contained.lifecycle(ctx, event, data, env);
-        // This can't be factored out as there is no common trait between contexts
-        if self.check_for_changes(data, env) {
+        #[cfg(feature = "druid-master")]
+        match event {
+            LifeCycle::DisabledChanged(disabled) => self.disabled = *disabled,
+            LifeCycle::ViewContextChanged(view) => {
+                let visible = view.clip.intersect(ctx.size().to_rect()).area() > 0.0;
+                self.visible = visible;
+                let outdated = matches!(&self.visibility, Some(v) if v.visible(data) != visible);
+                // Data can only be changed in event, so get one
+                if outdated && !std::mem::replace(&mut self.apply_submitted, true) {
+                    ctx.submit_command(APPLY_BINDINGS.to(ctx.widget_id()));
+                }
+            }
+            _ => (),
+        }
+        if let LifeCycle::WidgetAdded = event {
+            for hooks in &mut self.hooks {
+                hooks.on_init(self.contained.bindable_mut(), ctx, data, env);
+            }
+            if self.binding.wants_initial_data() {
+                // Data can only be written to the widget in update, so get one
+                self.initial_data_pending = true;
+                self.apply_submitted = true;
+                ctx.submit_command(APPLY_BINDINGS.to(ctx.widget_id()));
+            }
+            if let Some(interval) = self.refresh {
+                self.refresh_timer = ctx.request_timer(interval);
+            }
+        }
+        let origin = match event {
+            LifeCycle::WidgetAdded => ChangeOrigin::Init,
+            _ => ChangeOrigin::Programmatic,
+        };
+        if self.check_for_changes(data, env, origin) && self.apply_needed(ctx.widget_id()) {
             ctx.submit_command(APPLY_BINDINGS.to(ctx.widget_id()));
         }
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        #[cfg(feature = "druid-master")]
+        {
+            self.disabled = ctx.is_disabled();
+        }
         if !old_data.same(data) {
             self.binding
                 .apply_data_to_controlled(data, self.contained.bindable_mut(), ctx, env);
+            #[cfg(feature = "metrics")]
+            {
+                self.metrics.counts.data_to_widget += 1;
+            }
+        } else if ctx.env_changed() {
+            self.binding
+                .apply_env_to_controlled(data, self.contained.bindable_mut(), ctx, env);
+            #[cfg(feature = "metrics")]
+            {
+                if self.binding.env_dependent() {
+                    self.metrics.counts.data_to_widget += 1;
+                } else {
+                    self.metrics.counts.skipped += 1;
+                }
+            }
+        } else {
+            #[cfg(feature = "metrics")]
+            {
+                self.metrics.counts.skipped += 1;
+            }
+        }
+        if self.resync_pending {
+            self.resync_pending = false;
+            self.binding
+                .apply_data_to_controlled(data, self.contained.bindable_mut(), ctx, env);
+        }
+        let origin = if self.initial_data_pending {
+            ChangeOrigin::Init
+        } else {
+            ChangeOrigin::Programmatic
+        };
+        if self.initial_data_pending {
+            self.initial_data_pending = false;
+            self.binding.apply_initial_data_to_controlled(
+                data,
+                self.contained.bindable_mut(),
+                ctx,
+                env,
+            );
         }
         self.contained.update(ctx, old_data, data, env);
-        if self.check_for_changes(data, env) {
+        if self.check_for_changes(data, env, origin) && self.apply_needed(ctx.widget_id()) {
             ctx.submit_command(APPLY_BINDINGS.to(ctx.widget_id()));
         }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
         let size = self.contained.layout(ctx, bc, data, env);
-        if self.check_for_changes(data, env) {
+        if self.check_for_changes(data, env, ChangeOrigin::Programmatic)
+            && self.apply_needed(ctx.widget_id())
+        {
             ctx.submit_command(APPLY_BINDINGS.to(ctx.widget_id()));
         }
         size
     }
 
+    fn id(&self) -> Option<WidgetId> {
+        self.id.or_else(|| self.contained.id())
+    }
+
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
         self.contained.paint(ctx, data, env);
         // Can't submit commands from here currently.
@@ -573,3 +2143,306 @@ impl<
         // have to assume that any bound state will get picked up later
     }
 }
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{EditMock, MockValueProperty, MockWidget, EDIT_MOCK};
+    use druid::tests::harness::Harness;
+    use druid::widget::{ControllerHost, Flex};
+    use druid::{Lens, WidgetExt};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Clone, Data, Lens)]
+    struct State {
+        value: i32,
+        disabled: bool,
+    }
+
+    type MockHost = BindingHost<
+        State,
+        State,
+        ControllerHost<MockWidget<i32>, EditMock>,
+        MockWidget<i32>,
+        LensPropBinding<
+            State,
+            MockWidget<i32>,
+            i32,
+            state_derived_lenses::value,
+            MockValueProperty<i32>,
+        >,
+    >;
+
+    fn mock_host() -> MockHost {
+        BindingHost::new(
+            ControllerHost::new(MockWidget::new(0), EditMock::default()),
+            LensPropBinding::new(State::value, MockValueProperty::default()),
+        )
+    }
+
+    /// The value of the data after editing the widget under host to 5
+    fn edited(host: MockHost, disabled: bool) -> i32 {
+        let id = WidgetId::next();
+        let root = host
+            .disabled_if(|data: &State, _| data.disabled)
+            .with_id(id);
+        let data = State { value: 1, disabled };
+        let mut value = 0;
+        Harness::create_simple(data, root, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.submit_command(EDIT_MOCK.with(5).to(id));
+            value = harness.data().value;
+        });
+        value
+    }
+
+    /// Records the order bindings write the initial data in
+    struct Recorder {
+        name: &'static str,
+        order: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Binding<State, MockWidget<i32>> for Recorder {
+        type Change = ();
+
+        fn apply_data_to_controlled(
+            &self,
+            _data: &State,
+            _controlled: &mut MockWidget<i32>,
+            _ctx: &mut UpdateCtx,
+            _env: &Env,
+        ) {
+        }
+
+        fn append_change_required(
+            &self,
+            _controlled: &MockWidget<i32>,
+            _data: &State,
+            _change: &mut Option<Self::Change>,
+            _env: &Env,
+        ) {
+        }
+
+        fn apply_change_to_data(
+            &self,
+            _controlled: &MockWidget<i32>,
+            _data: &mut State,
+            _change: Self::Change,
+            _ctx: &mut EventCtx,
+            _env: &Env,
+        ) {
+        }
+
+        fn wants_initial_data(&self) -> bool {
+            true
+        }
+
+        fn apply_initial_data_to_controlled(
+            &self,
+            _data: &State,
+            _controlled: &mut MockWidget<i32>,
+            _ctx: &mut UpdateCtx,
+            _env: &Env,
+        ) {
+            self.order.borrow_mut().push(self.name)
+        }
+    }
+
+    /// The order three bindings with the given priorities, combined as (a.and(b)).and(c), are applied in
+    fn applied_order(a: i32, b: i32, c: i32) -> Vec<&'static str> {
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let recorder = |name| Recorder {
+            name,
+            order: order.clone(),
+        };
+        let binding = recorder("a")
+            .with_priority(a)
+            .and(recorder("b").with_priority(b))
+            .and(recorder("c").with_priority(c));
+        let host = BindingHost::<State, State, _, _, _>::new(MockWidget::new(0), binding);
+        let data = State {
+            value: 0,
+            disabled: false,
+        };
+        Harness::create_simple(data, host, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+        });
+        let order = order.borrow().clone();
+        order
+    }
+
+    #[test]
+    fn priorities_order_the_sides_of_each_pair() {
+        assert_eq!(applied_order(0, 0, 0), ["a", "b", "c"]);
+        assert_eq!(applied_order(0, 1, 0), ["b", "a", "c"]);
+        assert_eq!(applied_order(0, 0, 1), ["c", "a", "b"]);
+        // The pair of a and b goes first at a's priority, so b stays ahead of c despite c's higher priority
+        assert_eq!(applied_order(2, 0, 1), ["a", "b", "c"]);
+    }
+
+    /// Writes one more than the data to a MockWidget, so the widget and the data never agree
+    #[derive(Default)]
+    struct Contrary;
+
+    impl BindableProperty for Contrary {
+        type Controlled = MockWidget<i32>;
+        type Value = i32;
+        type Change = ();
+
+        fn write_prop(
+            &self,
+            controlled: &mut Self::Controlled,
+            _ctx: &mut UpdateCtx,
+            field_val: &Self::Value,
+            _env: &Env,
+        ) {
+            controlled.value = field_val + 1
+        }
+
+        fn append_changes(
+            &self,
+            controlled: &Self::Controlled,
+            field_val: &Self::Value,
+            change: &mut Option<Self::Change>,
+            _env: &Env,
+        ) {
+            if controlled.value != *field_val {
+                *change = Some(())
+            }
+        }
+
+        fn update_data_from_change(
+            &self,
+            controlled: &Self::Controlled,
+            _ctx: &EventCtx,
+            field: &mut Self::Value,
+            _change: Self::Change,
+            _env: &Env,
+        ) {
+            *field = controlled.value
+        }
+    }
+
+    #[test]
+    fn round_trips_dont_lead_to_more() {
+        let (id, other) = (WidgetId::next(), WidgetId::next());
+        let host = BindingHost::<State, State, _, _, _>::new(
+            ControllerHost::new(MockWidget::new(0), EditMock::default()),
+            LensPropBinding::new(State::value, Contrary),
+        )
+        .with_id(id);
+        let root = Flex::row()
+            .with_child(host)
+            .with_child(WidgetExt::<State>::with_id(MockWidget::new(0), other));
+        let data = State {
+            value: 0,
+            disabled: false,
+        };
+        Harness::create_simple(data, root, |harness| {
+            // Run the commands submitted in the last update, as druid does when idle,
+            // with events the host doesn't see
+            let idle = |harness: &mut Harness<State>| {
+                for _ in 0..5 {
+                    harness.submit_command(Selector::NOOP.to(other))
+                }
+            };
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.submit_command(EDIT_MOCK.with(5).to(id));
+            idle(harness);
+            // The edit is applied, and the 6 written back is applied in a round trip,
+            // but the 7 written back from that waits for the next event
+            assert_eq!(harness.data().value, 6);
+            harness.submit_command(EDIT_MOCK.with(10).to(id));
+            idle(harness);
+            assert_eq!(harness.data().value, 11);
+        });
+    }
+
+    #[test]
+    fn paused_hosts_drop_changes_while_disabled() {
+        assert_eq!(edited(mock_host().pause_when_disabled(), true), 1);
+    }
+
+    #[test]
+    fn paused_hosts_sync_while_enabled() {
+        assert_eq!(edited(mock_host().pause_when_disabled(), false), 5);
+    }
+
+    #[test]
+    fn hosts_sync_while_disabled_unless_paused() {
+        assert_eq!(edited(mock_host(), true), 5);
+    }
+
+    #[cfg(feature = "druid-widgets")]
+    mod visibility {
+        use super::*;
+        use crate::ScrollToProperty;
+        use druid::widget::{Axis, Controller, Flex, Scroll, SizedBox};
+
+        #[derive(Clone, Data, Lens)]
+        struct Scrolled {
+            offset: f64,
+            visible: bool,
+        }
+
+        const SCROLL_TO: Selector<f64> = Selector::new("druid-bindings.test.scroll-to");
+
+        /// Sets the offset the Scroll is bound to on SCROLL_TO
+        struct Scroller;
+
+        impl<W: Widget<Scrolled>> Controller<Scrolled, W> for Scroller {
+            fn event(
+                &mut self,
+                child: &mut W,
+                ctx: &mut EventCtx,
+                event: &Event,
+                data: &mut Scrolled,
+                env: &Env,
+            ) {
+                match event {
+                    Event::Command(c) if c.is(SCROLL_TO) => {
+                        data.offset = *c.get_unchecked(SCROLL_TO)
+                    }
+                    _ => child.event(ctx, event, data, env),
+                }
+            }
+        }
+
+        #[test]
+        fn hosts_report_whether_they_are_scrolled_into_view() {
+            let row = BindingHost::<Scrolled, Scrolled, _, _, _>::new(
+                MockWidget::new(0.),
+                LensPropBinding::new(Scrolled::offset, MockValueProperty::default()),
+            )
+            .bind_visibility(Scrolled::visible);
+            let rows = Flex::column()
+                .with_child(SizedBox::empty().fix_height(1000.))
+                .with_child(row.fix_size(100., 100.));
+            let scroll = Scroll::new(rows).vertical().binding(LensPropBinding::new(
+                Scrolled::offset,
+                ScrollToProperty::new(Axis::Vertical),
+            ));
+            let root = ControllerHost::new(scroll, Scroller);
+            let data = Scrolled {
+                offset: 0.,
+                visible: true,
+            };
+            Harness::create_simple(data, root, |harness| {
+                harness.send_initial_events();
+                harness.just_layout();
+                harness.submit_command(Selector::NOOP);
+                assert!(!harness.data().visible);
+                harness.submit_command(SCROLL_TO.with(800.));
+                harness.submit_command(Selector::NOOP);
+                assert!(harness.data().visible);
+                harness.submit_command(SCROLL_TO.with(0.));
+                harness.submit_command(Selector::NOOP);
+                assert!(!harness.data().visible);
+            });
+        }
+    }
+}
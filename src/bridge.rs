@@ -0,0 +1,96 @@
+use crate::binding::{BindableProperty, LensBindingExt, WidgetBindingExt};
+use crate::BindableAccess;
+use druid::widget::{DefaultScopePolicy, Scope};
+use druid::{Data, Lens, Widget, WidgetExt};
+
+/// The state inside a bridge: the outer data, plus the hidden field the bridged properties are bound to.
+#[derive(Clone, Data)]
+pub struct Bridged<T, V> {
+    /// The data from outside the bridge
+    pub data: T,
+    /// The value shared between the bridged properties
+    pub shared: V,
+}
+
+/// A lens from the state inside a bridge to the outer data
+pub struct BridgedData;
+
+impl<T, V> Lens<Bridged<T, V>, T> for BridgedData {
+    fn with<R, F: FnOnce(&T) -> R>(&self, data: &Bridged<T, V>, f: F) -> R {
+        f(&data.data)
+    }
+
+    fn with_mut<R, F: FnOnce(&mut T) -> R>(&self, data: &mut Bridged<T, V>, f: F) -> R {
+        f(&mut data.data)
+    }
+}
+
+/// A lens from the state inside a bridge to the value shared by the bridged properties
+pub struct BridgedShared;
+
+impl<T, V> Lens<Bridged<T, V>, V> for BridgedShared {
+    fn with<R, F: FnOnce(&V) -> R>(&self, data: &Bridged<T, V>, f: F) -> R {
+        f(&data.shared)
+    }
+
+    fn with_mut<R, F: FnOnce(&mut V) -> R>(&self, data: &mut Bridged<T, V>, f: F) -> R {
+        f(&mut data.shared)
+    }
+}
+
+/// Tie a property on one widget directly to a property on another, without a field in the app data.
+///
+/// The value lives in a hidden field of a Scope managed by the bridge, starting at V::default().
+/// Both widgets are bound to it, and then handed to `layout` to be placed in a container.
+/// Any other widgets the layout adds can reach the outer data through the BridgedData lens.
+/// This needs druid master, as the bindings reach the widgets through the LensWrap onto the outer data.
+///
+/// ```ignore
+/// bridge(
+///     Scroll::new(left),
+///     AxisFractionProperty::VERTICAL,
+///     Scroll::new(right),
+///     AxisFractionProperty::VERTICAL,
+///     |a, b| Flex::row().with_flex_child(a, 0.5).with_flex_child(b, 0.5),
+/// )
+/// ```
+pub fn bridge<T, V, UA, UB, A, B, PA, PB, W>(
+    a: A,
+    prop_a: PA,
+    b: B,
+    prop_b: PB,
+    layout: impl FnOnce(Box<dyn Widget<Bridged<T, V>>>, Box<dyn Widget<Bridged<T, V>>>) -> W,
+) -> impl Widget<T>
+where
+    T: Data,
+    V: Data + Default,
+    A: Widget<T> + BindableAccess<Wrapped = PA::Controlled> + 'static,
+    B: Widget<T> + BindableAccess<Wrapped = PB::Controlled> + 'static,
+    PA: BindableProperty<Value = V> + 'static,
+    PB: BindableProperty<Value = V> + 'static,
+    PA::Controlled: Widget<UA> + 'static,
+    PB::Controlled: Widget<UB> + 'static,
+    UA: 'static,
+    UB: 'static,
+    W: Widget<Bridged<T, V>> + 'static,
+{
+    let a: Box<dyn Widget<Bridged<T, V>>> = a
+        .lens(BridgedData)
+        .binding(BridgedShared.bind(prop_a))
+        .boxed();
+    let b: Box<dyn Widget<Bridged<T, V>>> = b
+        .lens(BridgedData)
+        .binding(BridgedShared.bind(prop_b))
+        .boxed();
+
+    Scope::new(
+        DefaultScopePolicy::from_lens(
+            |data: T| Bridged {
+                data,
+                shared: V::default(),
+            },
+            BridgedData,
+        ),
+        layout(a, b),
+    )
+}
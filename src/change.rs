@@ -0,0 +1,249 @@
+use std::ops::{Add, Range, Sub};
+
+/// A change to a property that can absorb a later change, so that several changes made to a widget
+/// between two events are applied to the data as one.
+pub trait Conflate {
+    /// Fold a later change into this one
+    fn conflate(&mut self, later: Self);
+}
+
+/// Record a new change in a property's pending change, conflating it with any change already there.
+/// This is the usual body of BindableProperty::append_changes once a change has been detected.
+pub fn append_change<C: Conflate>(change: &mut Option<C>, new: C) {
+    match change {
+        Some(existing) => existing.conflate(new),
+        None => *change = Some(new),
+    }
+}
+
+/// The plain "something changed" change, where the value is read from the controlled item when it is applied.
+impl Conflate for () {
+    fn conflate(&mut self, _later: Self) {}
+}
+
+/// A change carrying the latest value seen on the controlled item, and how many changes it stands for.
+/// Use it when the value can't cheaply be read back from the controlled item at apply time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Versioned<V> {
+    /// The latest value
+    pub value: V,
+    /// The number of changes conflated into this one
+    pub version: u64,
+}
+
+impl<V> Versioned<V> {
+    /// A first change to value
+    pub fn new(value: V) -> Self {
+        Versioned { value, version: 1 }
+    }
+}
+
+impl<V> Conflate for Versioned<V> {
+    fn conflate(&mut self, later: Self) {
+        self.value = later.value;
+        self.version += later.version;
+    }
+}
+
+/// A change recording how far a value has moved, rather than where it ended up.
+/// Deltas add up as they are conflated, and are applied by adding them to the field,
+/// so they compose with other writers of the same field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta<V>(pub V);
+
+impl<V: Sub<Output = V>> Delta<V> {
+    /// The delta taking the value from old to new
+    pub fn between(old: V, new: V) -> Self {
+        Delta(new - old)
+    }
+}
+
+impl<V: Add<Output = V> + Clone> Delta<V> {
+    /// Apply this delta to a field
+    pub fn apply_to(self, field: &mut V) {
+        *field = field.clone() + self.0
+    }
+}
+
+impl<V: Add<Output = V> + Clone> Conflate for Delta<V> {
+    fn conflate(&mut self, later: Self) {
+        self.0 = self.0.clone() + later.0
+    }
+}
+
+/// One replacement in a TextDelta: the byte range of the old text that was replaced, and what replaced it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    /// The replaced range, in bytes of the text before this edit
+    pub range: Range<usize>,
+    /// The text inserted in place of the range
+    pub text: String,
+}
+
+/// A change to some text recording only the ranges that were replaced, so that edits to a large
+/// text can be applied to a field without copying all of it. Edits are kept in the order they were
+/// made, each relative to the text left by those before it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextDelta {
+    /// The edits, in order
+    pub edits: Vec<TextEdit>,
+}
+
+impl TextDelta {
+    /// The single edit that turns old into new, replacing whatever lies between their common prefix and suffix
+    pub fn between(old: &str, new: &str) -> Self {
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, o), n)| o != n)
+            .map(|((idx, _), _)| idx)
+            .unwrap_or_else(|| old.len().min(new.len()));
+        let suffix: usize = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(o, n)| o == n)
+            .map(|(o, _)| o.len_utf8())
+            .sum();
+        if prefix + suffix == old.len() && prefix + suffix == new.len() {
+            return TextDelta::default();
+        }
+        TextDelta {
+            edits: vec![TextEdit {
+                range: prefix..old.len() - suffix,
+                text: new[prefix..new.len() - suffix].to_string(),
+            }],
+        }
+    }
+
+    /// Whether this leaves the text as it was
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Apply the edits to a field, in place, returning whether they all fitted it.
+    /// An edit whose range is past the end of the field or splits a character, as when the field
+    /// has moved on from the text the delta was made against, is not applied, nor are those after it,
+    /// so the field is left part edited. Copy the whole text into it instead.
+    pub fn apply_to(self, field: &mut String) -> bool {
+        for edit in self.edits {
+            let fits = edit.range.start <= edit.range.end
+                && edit.range.end <= field.len()
+                && field.is_char_boundary(edit.range.start)
+                && field.is_char_boundary(edit.range.end);
+            if !fits {
+                return false;
+            }
+            field.replace_range(edit.range, &edit.text)
+        }
+        true
+    }
+}
+
+impl Conflate for TextDelta {
+    fn conflate(&mut self, later: Self) {
+        self.edits.extend(later.edits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_changes_keep_the_latest_value_and_count() {
+        let mut change = None;
+        append_change(&mut change, Versioned::new(1));
+        append_change(&mut change, Versioned::new(2));
+        append_change(&mut change, Versioned::new(3));
+        assert_eq!(
+            change,
+            Some(Versioned {
+                value: 3,
+                version: 3
+            })
+        );
+    }
+
+    #[test]
+    fn deltas_add_up() {
+        let mut change = None;
+        append_change(&mut change, Delta::between(1.0, 3.0));
+        append_change(&mut change, Delta::between(3.0, 2.5));
+        let mut field = 10.0;
+        change.unwrap().apply_to(&mut field);
+        assert_eq!(field, 11.5);
+    }
+
+    fn round_trip(old: &str, new: &str) {
+        let mut field = old.to_string();
+        assert!(TextDelta::between(old, new).apply_to(&mut field));
+        assert_eq!(field, new, "applying the delta from {:?} to {:?}", old, new);
+    }
+
+    #[test]
+    fn text_deltas_round_trip() {
+        let texts = [
+            "",
+            "a",
+            "abc",
+            "abd",
+            "xabc",
+            "naïve",
+            "naive",
+            "café au lait",
+            "cafè au lait",
+            "日本語",
+            "日本",
+            "本語",
+            "👍",
+            "👍🏽",
+            "a👍🏽b",
+        ];
+        for old in texts.iter() {
+            for new in texts.iter() {
+                round_trip(old, new)
+            }
+        }
+    }
+
+    #[test]
+    fn text_deltas_replace_only_what_changed() {
+        assert!(TextDelta::between("naïve", "naïve").is_empty());
+        assert_eq!(
+            TextDelta::between("café au lait", "cafè au lait").edits,
+            vec![TextEdit {
+                range: 3..5,
+                text: "è".into()
+            }]
+        );
+        assert_eq!(
+            TextDelta::between("日本語", "日本").edits,
+            vec![TextEdit {
+                range: 6..9,
+                text: "".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn conflated_text_deltas_apply_in_order() {
+        let mut delta = TextDelta::between("ab", "aéb");
+        delta.conflate(TextDelta::between("aéb", "aé"));
+        let mut field = "ab".to_string();
+        assert!(delta.apply_to(&mut field));
+        assert_eq!(field, "aé");
+    }
+
+    #[test]
+    fn text_deltas_dont_apply_to_text_they_dont_fit() {
+        let delta = TextDelta::between("abcdef", "abcxyz");
+        let mut shorter = "abc".to_string();
+        assert!(!delta.clone().apply_to(&mut shorter));
+        assert_eq!(shorter, "abc");
+        let delta = TextDelta::between("a", "ab");
+        let mut split = "é".to_string();
+        assert!(!delta.apply_to(&mut split));
+        assert_eq!(split, "é");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::binding::BindableProperty;
+use druid::widget::prelude::*;
+use druid::Data;
+use std::marker::PhantomData;
+
+/// Wraps a widget, counting the clicks it receives: a mouse down and mouse up both within the widget.
+/// Binding ClickCountProperty to a field lets clicks be observed through the data, even when the clicked
+/// widget sits under a different data scope from whatever needs to react.
+pub struct ClickCounter<T, W> {
+    inner: W,
+    clicks: u64,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> ClickCounter<T, W> {
+    /// Count the clicks on the inner widget
+    pub fn new(inner: W) -> Self {
+        ClickCounter {
+            inner,
+            clicks: 0,
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The number of clicks so far
+    pub fn clicks(&self) -> u64 {
+        self.clicks
+    }
+}
+
+impl<T, W> crate::BindableAccess for ClickCounter<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for ClickCounter<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(_) => ctx.set_active(true),
+            Event::MouseUp(_) if ctx.is_active() && ctx.is_hot() => self.clicks += 1,
+            _ => (),
+        }
+        self.inner.event(ctx, event, data, env);
+        if let Event::MouseUp(_) = event {
+            ctx.set_active(false)
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// A read-only property exposing the click count of a ClickCounter.
+/// The bound field pulses: it increases by one on each click.
+pub struct ClickCountProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ClickCountProperty<T, W> {
+    fn default() -> Self {
+        ClickCountProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ClickCountProperty<T, W> {
+    type Controlled = ClickCounter<T, W>;
+    type Value = u64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.clicks().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.clicks()
+    }
+}
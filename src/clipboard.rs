@@ -0,0 +1,228 @@
+use crate::binding::BindableProperty;
+use druid::{Application, Env, EventCtx, UpdateCtx};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+/// A two way property binding a String field to the system clipboard.
+/// The clipboard is not part of any widget, so this can be bound on any controlled item,
+/// usually whatever widget shows the clipboard contents.
+///
+/// Writing the field puts its text on the clipboard.
+/// The clipboard is polled rather than watched: it is read once when the host first checks for changes,
+/// and after that only when the host writes the data to the widget again without the field having changed,
+/// ie on each tick of [`BindingHost::refresh_every`](crate::BindingHost::refresh_every) or on
+/// [`SYNC_FROM_DATA`](crate::SYNC_FROM_DATA).
+/// Checking for changes after events never touches the clipboard, so text copied in other applications
+/// only shows up in the field if the host is refreshed, eg `.refresh_every(Duration::from_millis(500))`.
+/// An empty field stands for a clipboard without text.
+pub struct ClipboardProperty<C> {
+    get: fn() -> String,
+    put: fn(&str),
+    /// The clipboard text as of the last time it was read or written
+    known: RefCell<Option<String>>,
+    /// Text found by polling that the field has not caught up with yet
+    polled: RefCell<Option<String>>,
+    phantom_c: PhantomData<C>,
+}
+
+impl<C> Default for ClipboardProperty<C> {
+    fn default() -> Self {
+        ClipboardProperty {
+            get: clipboard_text,
+            put: put_clipboard_text,
+            known: Default::default(),
+            polled: Default::default(),
+            phantom_c: Default::default(),
+        }
+    }
+}
+
+fn clipboard_text() -> String {
+    Application::global()
+        .clipboard()
+        .get_string()
+        .unwrap_or_default()
+}
+
+fn put_clipboard_text(text: &str) {
+    Application::global().clipboard().put_string(text)
+}
+
+impl<C> ClipboardProperty<C> {
+    fn poll(&self, field_val: &str) {
+        let text = (self.get)();
+        let changed = text != field_val;
+        *self.known.borrow_mut() = Some(text.clone());
+        *self.polled.borrow_mut() = if changed { Some(text) } else { None };
+    }
+}
+
+impl<C> BindableProperty for ClipboardProperty<C> {
+    type Controlled = C;
+    type Value = String;
+    type Change = String;
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        let unchanged = self.known.borrow().as_ref() == Some(field_val);
+        if unchanged {
+            // Nothing new to put on the clipboard, so this is a refresh
+            self.poll(field_val)
+        } else {
+            (self.put)(field_val);
+            *self.known.borrow_mut() = Some(field_val.clone());
+            *self.polled.borrow_mut() = None;
+        }
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if self.known.borrow().is_none() {
+            self.poll(field_val)
+        }
+        if let Some(text) = self.polled.borrow().as_ref() {
+            if text != field_val {
+                *change = Some(text.clone())
+            }
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        _env: &Env,
+    ) {
+        *self.polled.borrow_mut() = None;
+        *field = change
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::MockWidget;
+    use crate::{BindingHost, LensBindingExt, SYNC_FROM_DATA};
+    use druid::tests::harness::Harness;
+    use druid::widget::prelude::*;
+    use druid::widget::{Controller, ControllerHost};
+    use druid::{lens, Selector};
+    use std::cell::Cell;
+
+    thread_local! {
+        static CLIPBOARD: RefCell<String> = const { RefCell::new(String::new()) };
+        static READS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn fake_text() -> String {
+        READS.with(|reads| reads.set(reads.get() + 1));
+        CLIPBOARD.with(|clipboard| clipboard.borrow().clone())
+    }
+
+    fn fake_put(text: &str) {
+        CLIPBOARD.with(|clipboard| *clipboard.borrow_mut() = text.to_string())
+    }
+
+    /// Copy text in "another application"
+    fn copy(text: &str) {
+        fake_put(text)
+    }
+
+    /// What is on the clipboard, without counting as a read
+    fn copied() -> String {
+        CLIPBOARD.with(|clipboard| clipboard.borrow().clone())
+    }
+
+    fn fake_clipboard() -> ClipboardProperty<MockWidget<()>> {
+        ClipboardProperty {
+            get: fake_text,
+            put: fake_put,
+            ..Default::default()
+        }
+    }
+
+    /// Write the field, as app logic would
+    const WRITE: Selector<String> = Selector::new("druid-bindings.test.write-clipboard");
+
+    struct Writer;
+
+    impl<W: Widget<String>> Controller<String, W> for Writer {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut String,
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(c) if c.is(WRITE) => *data = c.get_unchecked(WRITE).clone(),
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    fn with_clipboard(copied: &str, mut f: impl FnMut(&mut Harness<String>)) {
+        copy(copied);
+        READS.with(|reads| reads.set(0));
+        let host = BindingHost::<String, String, _, _, _>::new(
+            MockWidget::new(()),
+            lens::Identity.bind(fake_clipboard()),
+        );
+        Harness::create_simple(
+            String::new(),
+            ControllerHost::new(host, Writer),
+            |harness| {
+                harness.send_initial_events();
+                harness.just_layout();
+                f(harness)
+            },
+        );
+    }
+
+    fn reads() -> usize {
+        READS.with(|reads| reads.get())
+    }
+
+    #[test]
+    fn the_clipboard_is_read_once_then_only_on_refresh() {
+        with_clipboard("first", |harness| {
+            assert_eq!(harness.data(), "first");
+            let initial_reads = reads();
+            for _ in 0..5 {
+                harness.submit_command(Selector::NOOP);
+            }
+            assert_eq!(reads(), initial_reads);
+            copy("second");
+            harness.submit_command(Selector::NOOP);
+            assert_eq!(harness.data(), "first");
+            harness.submit_command(SYNC_FROM_DATA);
+            harness.submit_command(Selector::NOOP);
+            assert_eq!(harness.data(), "second");
+        });
+    }
+
+    #[test]
+    fn writing_the_field_puts_it_on_the_clipboard_without_reading() {
+        with_clipboard("copied", |harness| {
+            let initial_reads = reads();
+            harness.submit_command(WRITE.with("written".to_string()));
+            harness.submit_command(Selector::NOOP);
+            assert_eq!(copied(), "written");
+            assert_eq!(reads(), initial_reads);
+            assert_eq!(harness.data(), "written");
+        });
+    }
+}
@@ -0,0 +1,264 @@
+use crate::binding::BindableProperty;
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::{Data, Point, Rect, WidgetPod};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// A header with a body under it that can be shown and hidden, for accordions and "more options" sections.
+/// Clicking the header toggles the body, and the body slides open and closed rather than jumping.
+///
+/// Bind CollapsibleExpandedProperty to keep whether it is expanded in the data, eg to let
+/// only one section of an accordion be open, or to remember which sections were open.
+pub struct Collapsible<T, H, W> {
+    header: WidgetPod<T, H>,
+    body: WidgetPod<T, W>,
+    expanded: bool,
+    /// How far open the body is, from 0 (collapsed) to 1 (expanded)
+    openness: f64,
+    duration: Duration,
+}
+
+impl<T, H: Widget<T>, W: Widget<T>> Collapsible<T, H, W> {
+    /// Show header, with body under it once expanded. It starts collapsed.
+    pub fn new(header: H, body: W) -> Self {
+        Collapsible {
+            header: WidgetPod::new(header),
+            body: WidgetPod::new(body),
+            expanded: false,
+            openness: 0.,
+            duration: Duration::from_millis(150),
+        }
+    }
+
+    /// Builder-style method to start expanded
+    pub fn expanded(mut self) -> Self {
+        self.expanded = true;
+        self.openness = 1.;
+        self
+    }
+
+    /// Builder-style method to set how long the body takes to open or close. Zero makes it jump.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Whether the body is shown, or on its way to being shown
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Show or hide the body. It moves there over the duration,
+    /// once the context has been asked for an animation frame.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+        if self.duration == Duration::from_secs(0) {
+            self.openness = self.target();
+        }
+    }
+
+    fn target(&self) -> f64 {
+        if self.expanded {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    fn animating(&self) -> bool {
+        self.openness != self.target()
+    }
+
+    fn step(&mut self, interval: u64) {
+        let step = Duration::from_nanos(interval).as_secs_f64() / self.duration.as_secs_f64();
+        self.openness = if self.expanded {
+            (self.openness + step).min(1.)
+        } else {
+            (self.openness - step).max(0.)
+        };
+    }
+
+    /// The fraction of the body's height that is shown, eased at either end
+    fn shown(&self) -> f64 {
+        let t = self.openness;
+        t * t * (3. - 2. * t)
+    }
+}
+
+impl<T, H, W> crate::BindableAccess for Collapsible<T, H, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, H: Widget<T>, W: Widget<T>> Widget<T> for Collapsible<T, H, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::AnimFrame(interval) if self.animating() => {
+                self.step(*interval);
+                if self.animating() {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_layout();
+            }
+            Event::MouseDown(_) if self.header.is_hot() => ctx.set_active(true),
+            Event::MouseUp(_) if ctx.is_active() => {
+                ctx.set_active(false);
+                if self.header.is_hot() {
+                    self.set_expanded(!self.expanded);
+                    ctx.request_anim_frame();
+                    ctx.request_layout();
+                }
+            }
+            _ => (),
+        }
+        self.header.event(ctx, event, data, env);
+        if self.openness > 0. {
+            self.body.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.header.lifecycle(ctx, event, data, env);
+        self.body.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.header.update(ctx, data, env);
+        self.body.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let header_size = self.header.layout(ctx, &bc.loosen(), data, env);
+        compat::set_origin(&mut self.header, ctx, data, env, Point::ORIGIN);
+
+        // The body is laid out at full height and clipped to the part that is shown
+        let body_bc = BoxConstraints::new(
+            Size::new(bc.min().width, 0.),
+            Size::new(bc.max().width, f64::INFINITY),
+        );
+        let body_size = self.body.layout(ctx, &body_bc, data, env);
+        let body_origin = Point::new(0., header_size.height);
+        compat::set_origin(&mut self.body, ctx, data, env, body_origin);
+
+        bc.constrain(Size::new(
+            header_size.width.max(body_size.width),
+            header_size.height + body_size.height * self.shown(),
+        ))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.header.paint(ctx, data, env);
+        if self.openness > 0. {
+            let size = ctx.size();
+            let top = self.header.layout_rect().height();
+            ctx.with_save(|ctx| {
+                ctx.clip(Rect::new(0., top, size.width, size.height));
+                self.body.paint(ctx, data, env);
+            });
+        }
+    }
+}
+
+/// A bindable property for whether a Collapsible is expanded.
+/// Clicks on the header are written to the data, and writes from the data animate the body open or closed.
+pub struct CollapsibleExpandedProperty<T, H, W> {
+    phantom_t: PhantomData<T>,
+    phantom_h: PhantomData<H>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, H, W> Default for CollapsibleExpandedProperty<T, H, W> {
+    fn default() -> Self {
+        CollapsibleExpandedProperty {
+            phantom_t: Default::default(),
+            phantom_h: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, H: Widget<T>, W: Widget<T>> BindableProperty
+    for CollapsibleExpandedProperty<T, H, W>
+{
+    type Controlled = Collapsible<T, H, W>;
+    type Value = bool;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        if controlled.is_expanded() != *field_val {
+            controlled.set_expanded(*field_val);
+            ctx.request_anim_frame();
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.is_expanded() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.is_expanded()
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockWidget, PropertyHarness};
+
+    fn collapsible() -> Collapsible<(), MockWidget<()>, MockWidget<()>> {
+        Collapsible::new(MockWidget::new(()), MockWidget::new(()))
+    }
+
+    #[test]
+    fn the_expanded_flag_is_written_and_read_back() {
+        PropertyHarness::run(
+            CollapsibleExpandedProperty::default(),
+            collapsible(),
+            true,
+            |harness| {
+                assert!(harness.controlled().is_expanded());
+                assert!(!harness.read_back());
+                harness.write(false);
+                assert!(!harness.controlled().is_expanded());
+                // As clicking the header would
+                harness.controlled_mut().set_expanded(true);
+                assert!(harness.read_back());
+                assert!(*harness.field());
+                assert!(!harness.read_back());
+            },
+        );
+    }
+
+    #[test]
+    fn collapsing_without_a_duration_jumps_shut() {
+        let mut collapsible = collapsible()
+            .expanded()
+            .with_duration(Duration::from_secs(0));
+        assert!(!collapsible.animating());
+        collapsible.set_expanded(false);
+        assert!(!collapsible.animating());
+        assert_eq!(collapsible.shown(), 0.);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::binding::BindableProperty;
+use crate::BindableAccess;
+use druid::widget::prelude::*;
+use druid::Selector;
+use std::marker::PhantomData;
+
+/// Wraps a bindable widget, writing the payload of a command straight into one of its properties,
+/// without going through the data. This suits one-off actions, such as scrolling to the top,
+/// that shouldn't be kept in the app state.
+///
+/// The command is taken in event and the property written in the update that follows, as properties
+/// can only be written in update. Send the command to this widget (eg through an IdentityWrapper),
+/// or to its window. Bindings can be put around this widget as they would around the one it wraps.
+pub struct CommandWriter<T, W, P: BindableProperty> {
+    inner: W,
+    selector: Selector<P::Value>,
+    prop: P,
+    pending: Option<P::Value>,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W, P> CommandWriter<T, W, P>
+where
+    W: Widget<T> + BindableAccess<Wrapped = P::Controlled>,
+    P: BindableProperty,
+    P::Value: Clone + 'static,
+{
+    /// Write the payload of each selector command reaching inner into prop
+    pub fn new(inner: W, selector: Selector<P::Value>, prop: P) -> Self {
+        CommandWriter {
+            inner,
+            selector,
+            prop,
+            pending: None,
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T, W: BindableAccess, P: BindableProperty> BindableAccess for CommandWriter<T, W, P> {
+    type Wrapped = W::Wrapped;
+
+    fn bindable(&self) -> &Self::Wrapped {
+        self.inner.bindable()
+    }
+
+    fn bindable_mut(&mut self) -> &mut Self::Wrapped {
+        self.inner.bindable_mut()
+    }
+}
+
+impl<T, W, P> Widget<T> for CommandWriter<T, W, P>
+where
+    W: Widget<T> + BindableAccess<Wrapped = P::Controlled>,
+    P: BindableProperty,
+    P::Value: Clone + 'static,
+{
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::Command(c) if c.is(self.selector) => {
+                self.pending = Some(c.get_unchecked(self.selector).clone());
+                ctx.request_update();
+            }
+            _ => self.inner.event(ctx, event, data, env),
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if let Some(value) = self.pending.take() {
+            self.prop
+                .write_prop(self.inner.bindable_mut(), ctx, &value, env)
+        }
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.inner.id()
+    }
+}
@@ -0,0 +1,88 @@
+// The few parts of druid's API this crate uses that differ between druid master and druid 0.7.
+// Where only the API differs, modules use these rather than testing the druid-master feature themselves.
+
+use druid::widget::prelude::*;
+use druid::widget::Viewport;
+use druid::{Point, Rect, WidgetPod};
+
+#[cfg(not(feature = "druid-master"))]
+pub(crate) use druid::text::format::Formatter;
+#[cfg(feature = "druid-master")]
+pub(crate) use druid::text::Formatter;
+
+#[cfg(all(feature = "druid-widgets", not(feature = "druid-master")))]
+pub(crate) use druid::theme::LABEL_COLOR as TEXT_COLOR;
+#[cfg(all(feature = "druid-widgets", feature = "druid-master"))]
+pub(crate) use druid::theme::TEXT_COLOR;
+
+/// Position child within its parent, from the parent's layout.
+/// Master no longer passes the data and env along, 0.7 needs them.
+#[cfg(feature = "druid-master")]
+pub(crate) fn set_origin<T: Data, W: Widget<T>>(
+    child: &mut WidgetPod<T, W>,
+    ctx: &mut LayoutCtx,
+    _data: &T,
+    _env: &Env,
+    origin: Point,
+) {
+    child.set_origin(ctx, origin)
+}
+
+#[cfg(not(feature = "druid-master"))]
+pub(crate) fn set_origin<T: Data, W: Widget<T>>(
+    child: &mut WidgetPod<T, W>,
+    ctx: &mut LayoutCtx,
+    data: &T,
+    env: &Env,
+    origin: Point,
+) {
+    child.set_origin(ctx, data, env, origin)
+}
+
+/// The menus druid shows as context menus
+#[cfg(feature = "druid-master")]
+pub(crate) type Menu<App> = druid::Menu<App>;
+#[cfg(not(feature = "druid-master"))]
+pub(crate) type Menu<App> = druid::MenuDesc<App>;
+
+/// Show menu as a context menu at position, in window coordinates
+#[cfg(feature = "druid-master")]
+pub(crate) fn show_context_menu<App: std::any::Any>(
+    ctx: &mut EventCtx,
+    menu: Menu<App>,
+    position: Point,
+) {
+    ctx.show_context_menu(menu, position)
+}
+
+#[cfg(not(feature = "druid-master"))]
+pub(crate) fn show_context_menu<App: std::any::Any>(
+    ctx: &mut EventCtx,
+    menu: Menu<App>,
+    position: Point,
+) {
+    ctx.show_context_menu(druid::ContextMenu::new(menu, position))
+}
+
+/// The visible part of the content, in content coordinates
+#[cfg(feature = "druid-master")]
+pub(crate) fn view_rect(port: &Viewport) -> Rect {
+    port.view_rect()
+}
+
+#[cfg(not(feature = "druid-master"))]
+pub(crate) fn view_rect(port: &Viewport) -> Rect {
+    port.rect
+}
+
+/// Move and resize the visible part of the content, without checking it stays within the content
+#[cfg(feature = "druid-master")]
+pub(crate) fn set_view_rect(port: &mut Viewport, rect: Rect) {
+    port.view_origin = rect.origin();
+    port.view_size = rect.size();
+}
+
+#[cfg(not(feature = "druid-master"))]
+pub(crate) fn set_view_rect(port: &mut Viewport, rect: Rect) {
+    port.rect = rect;
+}
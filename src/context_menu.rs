@@ -0,0 +1,145 @@
+use crate::binding::Binding;
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::{Lens, Point};
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+/// Wraps a widget, remembering where the pointer last was over it,
+/// so that a ContextMenuBinding can show its menu there.
+pub struct ContextMenuArea<T, W> {
+    inner: W,
+    last_pointer: Point,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> ContextMenuArea<T, W> {
+    /// Track the pointer over the inner widget
+    pub fn new(inner: W) -> Self {
+        ContextMenuArea {
+            inner,
+            last_pointer: Point::ORIGIN,
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T, W> ContextMenuArea<T, W> {
+    /// The last pointer position over this widget, in window coordinates
+    pub fn last_pointer(&self) -> Point {
+        self.last_pointer
+    }
+}
+
+impl<T, W> crate::BindableAccess for ContextMenuArea<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for ContextMenuArea<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(mouse) | Event::MouseUp(mouse) | Event::MouseMove(mouse) => {
+                self.last_pointer = mouse.window_pos
+            }
+            _ => (),
+        }
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// The menus a ContextMenuBinding shows: druid::Menu on druid master, druid::MenuDesc on druid 0.7
+pub type ContextMenuDesc<App> = compat::Menu<App>;
+
+/// Shows a context menu described by the data.
+///
+/// Writing Some(request) to the bound field makes the host show the menu built from that request
+/// at the last pointer position over a ContextMenuArea, then resets the field to None, ready for the next request.
+/// The request can be any app defined type, eg an enum of the menus the app can show; App is the type of the root data
+/// the menu's commands operate on.
+pub struct ContextMenuBinding<T, M, App, L: Lens<T, Option<M>>, F: Fn(&M) -> ContextMenuDesc<App>> {
+    lens_from_data: L,
+    build_menu: F,
+    pending: RefCell<Option<ContextMenuDesc<App>>>,
+    phantom_t: PhantomData<T>,
+    phantom_m: PhantomData<M>,
+}
+
+impl<T, M, App, L: Lens<T, Option<M>>, F: Fn(&M) -> ContextMenuDesc<App>>
+    ContextMenuBinding<T, M, App, L, F>
+{
+    /// Show menus built by build_menu from the requests written to the field at lens_from_data
+    pub fn new(lens_from_data: L, build_menu: F) -> Self {
+        ContextMenuBinding {
+            lens_from_data,
+            build_menu,
+            pending: RefCell::new(None),
+            phantom_t: Default::default(),
+            phantom_m: Default::default(),
+        }
+    }
+}
+
+impl<T, W, M, App: Any, L: Lens<T, Option<M>>, F: Fn(&M) -> ContextMenuDesc<App>>
+    Binding<T, ContextMenuArea<T, W>> for ContextMenuBinding<T, M, App, L, F>
+{
+    type Change = ();
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        _controlled: &mut ContextMenuArea<T, W>,
+        _ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+        // The menu can only be shown from an event, so hold it as a change until then.
+        self.lens_from_data.with(data, |request| {
+            if let Some(request) = request {
+                self.pending.replace(Some((self.build_menu)(request)));
+            }
+        })
+    }
+
+    fn append_change_required(
+        &self,
+        _controlled: &ContextMenuArea<T, W>,
+        _data: &T,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if self.pending.borrow().is_some() {
+            *change = Some(())
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &ContextMenuArea<T, W>,
+        data: &mut T,
+        _change: Self::Change,
+        ctx: &mut EventCtx,
+        _env: &Env,
+    ) {
+        if let Some(menu) = self.pending.borrow_mut().take() {
+            compat::show_context_menu(ctx, menu, controlled.last_pointer());
+        }
+        self.lens_from_data
+            .with_mut(data, |request| *request = None)
+    }
+}
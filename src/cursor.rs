@@ -0,0 +1,116 @@
+use crate::binding::BindableProperty;
+use druid::widget::prelude::*;
+use druid::Cursor;
+use std::marker::PhantomData;
+
+/// Wraps a widget, setting the mouse cursor while it is hovered.
+/// Bind CursorProperty to it to have the cursor follow the app's state, eg a pan or select tool mode.
+pub struct CursorArea<T, W> {
+    inner: W,
+    cursor: Cursor,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> CursorArea<T, W> {
+    /// Show the arrow cursor over the inner widget, until another is set
+    pub fn new(inner: W) -> Self {
+        CursorArea {
+            inner,
+            cursor: Cursor::Arrow,
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The cursor shown over the widget
+    pub fn cursor(&self) -> &Cursor {
+        &self.cursor
+    }
+
+    /// Set the cursor shown over the widget. It changes when the mouse next moves.
+    pub fn set_cursor(&mut self, cursor: Cursor) {
+        self.cursor = cursor
+    }
+}
+
+impl<T, W> crate::BindableAccess for CursorArea<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for CursorArea<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env);
+        // After the inner widget, so this cursor wins over any it sets
+        if let Event::MouseMove(_) = event {
+            if ctx.is_hot() {
+                ctx.set_cursor(&self.cursor)
+            }
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// A write-only property for the cursor a CursorArea shows.
+/// Cursor is not Data, so mark a Cursor field in the app's data with `#[data(same_fn = "PartialEq::eq")]`.
+pub struct CursorProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for CursorProperty<T, W> {
+    fn default() -> Self {
+        CursorProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for CursorProperty<T, W> {
+    type Controlled = CursorArea<T, W>;
+    type Value = Cursor;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_cursor(field_val.clone())
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
@@ -0,0 +1,137 @@
+use crate::binding::BindableProperty;
+use druid::{Env, EventCtx, Point, Scale, Size, UpdateCtx, Vec2};
+use std::cell::Cell;
+
+/// Geometry that can be converted between druid's logical pixels and device pixels.
+pub trait DeviceScalable: Sized {
+    /// Convert from logical to device pixels
+    fn to_device(&self, scale: Scale) -> Self;
+    /// Convert from device to logical pixels
+    fn to_logical(&self, scale: Scale) -> Self;
+}
+
+// Druid's scale is the same on both axes on every platform it supports, so lengths use the x scale.
+impl DeviceScalable for f64 {
+    fn to_device(&self, scale: Scale) -> Self {
+        self * scale.x()
+    }
+
+    fn to_logical(&self, scale: Scale) -> Self {
+        self / scale.x()
+    }
+}
+
+impl DeviceScalable for Size {
+    fn to_device(&self, scale: Scale) -> Self {
+        Size::new(self.width * scale.x(), self.height * scale.y())
+    }
+
+    fn to_logical(&self, scale: Scale) -> Self {
+        Size::new(self.width / scale.x(), self.height / scale.y())
+    }
+}
+
+impl DeviceScalable for Point {
+    fn to_device(&self, scale: Scale) -> Self {
+        Point::new(self.x * scale.x(), self.y * scale.y())
+    }
+
+    fn to_logical(&self, scale: Scale) -> Self {
+        Point::new(self.x / scale.x(), self.y / scale.y())
+    }
+}
+
+impl DeviceScalable for Vec2 {
+    fn to_device(&self, scale: Scale) -> Self {
+        Vec2::new(self.x * scale.x(), self.y * scale.y())
+    }
+
+    fn to_logical(&self, scale: Scale) -> Self {
+        Vec2::new(self.x / scale.x(), self.y / scale.y())
+    }
+}
+
+/// Adapts a geometry property so its data is in device pixels, using the scale of the window it is in.
+/// Useful when persisting geometry that should survive moving between monitors of different DPI.
+///
+/// Change detection has no context to ask the window for its scale, so it uses the scale last seen
+/// when writing or reading the property. Each write or read picks up the window's current scale.
+pub struct DevicePixelsProperty<P> {
+    prop: P,
+    scale: Cell<Scale>,
+}
+
+impl<P: BindableProperty> DevicePixelsProperty<P>
+where
+    P::Value: DeviceScalable,
+{
+    /// Bind prop in device pixels
+    pub fn new(prop: P) -> Self {
+        DevicePixelsProperty {
+            prop,
+            scale: Cell::new(Scale::new(1., 1.)),
+        }
+    }
+
+    fn refresh_scale(&self, scale: Option<Scale>) -> Scale {
+        if let Some(scale) = scale {
+            self.scale.set(scale)
+        }
+        self.scale.get()
+    }
+}
+
+impl<P: BindableProperty> BindableProperty for DevicePixelsProperty<P>
+where
+    P::Value: DeviceScalable,
+{
+    type Controlled = P::Controlled;
+    type Value = P::Value;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        let scale = self.refresh_scale(ctx.window().get_scale().ok());
+        self.prop
+            .write_prop(controlled, ctx, &field_val.to_logical(scale), env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let logical = field_val.to_logical(self.scale.get());
+        self.prop.append_changes(controlled, &logical, change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        let scale = self.refresh_scale(ctx.window().get_scale().ok());
+        let mut logical = field.to_logical(scale);
+        self.prop
+            .update_data_from_change(controlled, ctx, &mut logical, change, env);
+        *field = logical.to_device(scale)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.device_pixels()", self.prop.name())
+    }
+}
@@ -0,0 +1,76 @@
+use druid::WidgetId;
+
+/// What a BindingHost does on finding that its changes are stuck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StuckAction {
+    /// Log a warning, once per time the host gets stuck
+    Log,
+    /// Panic, for tests and debug builds
+    Panic,
+}
+
+/// Detects a BindingHost whose changes from the widget are never applied to the data.
+///
+/// A host queues changes found outside of event, and submits itself a command to apply them.
+/// If that command never arrives (eg the host isn't really in a window, or something swallows the
+/// command on the way) the binding silently stops working from widget to data.
+/// This counts the submissions made without an event arriving in between.
+pub(crate) struct StuckDetector {
+    threshold: usize,
+    action: StuckAction,
+    waiting: usize,
+}
+
+impl StuckDetector {
+    pub(crate) fn new(threshold: usize, action: StuckAction) -> Self {
+        StuckDetector {
+            threshold,
+            action,
+            waiting: 0,
+        }
+    }
+
+    /// Record that the host has asked for its pending changes to be applied
+    pub(crate) fn submitted(&mut self, id: WidgetId, describe: impl FnOnce() -> String) {
+        self.waiting += 1;
+        if self.waiting == self.threshold {
+            let message = format!(
+                "BindingHost {:?} ({}) has asked {} times for its changes to be applied without receiving an event",
+                id,
+                describe(),
+                self.waiting
+            );
+            match self.action {
+                StuckAction::Log => log::warn!("{}", message),
+                StuckAction::Panic => panic!("{}", message),
+            }
+        }
+    }
+
+    /// Record that the host's pending changes have been applied
+    pub(crate) fn applied(&mut self) {
+        self.waiting = 0;
+    }
+}
+
+/// The name of type T with the module paths dropped from it and its parameters,
+/// eg `LensPropBinding<u32, Label<u32>, String, Field<..>, LabelTextProperty<u32>>`.
+/// Used for the default descriptions of bindings and names of properties.
+pub fn short_type_name<T: ?Sized>() -> String {
+    let full = std::any::type_name::<T>();
+    let mut short = String::with_capacity(full.len());
+    let mut start = 0;
+    for (idx, c) in full.char_indices() {
+        if !(c.is_alphanumeric() || c == '_' || c == ':') {
+            push_last_segment(&mut short, &full[start..idx]);
+            short.push(c);
+            start = idx + c.len_utf8();
+        }
+    }
+    push_last_segment(&mut short, &full[start..]);
+    short
+}
+
+fn push_last_segment(short: &mut String, path: &str) {
+    short.push_str(path.rsplit("::").next().unwrap_or(path))
+}
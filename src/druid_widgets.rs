@@ -1,77 +1,236 @@
 use crate::bindable_access::*;
 use crate::binding::*;
+use crate::compat;
+use druid::text::{FontDescriptor, TextStorage};
 use druid::widget::prelude::*;
-use druid::widget::{Axis, IdentityWrapper, LensWrap, Scroll, WidgetWrapper};
+use druid::widget::{Label, LineBreaking, RawLabel};
+use druid::{theme, Color, Data, KeyOrValue, TextAlignment};
 use std::marker::PhantomData;
 
-impl<W: BindableAccess> BindableAccess for IdentityWrapper<W> {
-    bindable_wrapper_body!();
-}
+// The properties of Scroll, ClipBox and Tabs need accessors only found on druid's master branch
+#[cfg(feature = "druid-master")]
+mod scroll;
+#[cfg(feature = "druid-master")]
+mod tabs;
+#[cfg(feature = "druid-master")]
+pub use scroll::*;
+#[cfg(feature = "druid-master")]
+pub use tabs::*;
 
-impl<T, U, L, W: BindableAccess> BindableAccess for LensWrap<T, U, L, W> {
-    bindable_wrapper_body!();
+impl<T> BindableAccess for Label<T> {
+    bindable_self_body!();
 }
 
-impl<T, W> BindableAccess for Scroll<T, W> {
+impl<T> BindableAccess for RawLabel<T> {
     bindable_self_body!();
 }
 
-/// A bindable property to allow scroll offsets to be linked to app data.
-/// Useful within composite components with linked scroll areas (eg tables)
-pub struct ScrollToProperty<T, W> {
-    direction: Axis, // We have no public direction/axis type, but two private ones. Sigh.
-    phantom_t: PhantomData<T>,
+bindable_props! {
+    impl<T: Data> for Label<T> {
+        /// A write-only property for the text of a Label, replacing whatever it was built with
+        pub LabelTextProperty: String => set_text, request_layout;
+        /// A write-only property for the colour of a Label's text
+        pub LabelTextColorProperty: KeyOrValue<Color> => set_text_color, request_layout;
+        /// A write-only property for the size of a Label's text
+        pub LabelTextSizeProperty: KeyOrValue<f64> => set_text_size, request_layout;
+        /// A write-only property for the font of a Label
+        pub LabelFontProperty: KeyOrValue<FontDescriptor> => set_font, request_layout;
+        /// A write-only property for how a Label breaks lines that don't fit
+        pub LabelLineBreakingProperty: LineBreaking => set_line_break_mode, request_layout;
+        /// A write-only property for the alignment of a Label's text
+        pub LabelTextAlignmentProperty: TextAlignment => set_text_alignment, request_layout;
+    }
+}
+
+bindable_props! {
+    impl<T: TextStorage> for RawLabel<T> {
+        /// A write-only property for the alignment of a RawLabel's text
+        pub RawLabelTextAlignmentProperty: TextAlignment => set_text_alignment, request_layout;
+    }
+}
+
+/// The direction of the UI language, which decides which edge text aligned to its Start or End goes to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum TextDirection {
+    /// Start is the left edge
+    LeftToRight,
+    /// Start is the right edge
+    RightToLeft,
+}
+
+/// A text alignment along with the direction it is to be read in
+#[derive(Debug, Clone, Data)]
+pub struct DirectedAlignment {
+    /// The alignment, as if the text were left to right
+    #[data(same_fn = "PartialEq::eq")]
+    pub alignment: TextAlignment,
+    /// The direction of the UI language
+    pub direction: TextDirection,
+}
+
+impl DirectedAlignment {
+    /// The alignment to give the widget: Start and End swap for right to left.
+    pub fn resolve(&self) -> TextAlignment {
+        match (self.direction, self.alignment) {
+            (TextDirection::RightToLeft, TextAlignment::Start) => TextAlignment::End,
+            (TextDirection::RightToLeft, TextAlignment::End) => TextAlignment::Start,
+            (_, alignment) => alignment,
+        }
+    }
+}
+
+/// Widgets showing text that can be aligned, for DirectedAlignmentProperty
+pub trait TextAlignable {
+    /// Set the alignment of the text
+    fn set_alignment(&mut self, alignment: TextAlignment);
+}
+
+impl<T: Data> TextAlignable for Label<T> {
+    fn set_alignment(&mut self, alignment: TextAlignment) {
+        self.set_text_alignment(alignment)
+    }
+}
+
+impl<T: TextStorage> TextAlignable for RawLabel<T> {
+    fn set_alignment(&mut self, alignment: TextAlignment) {
+        self.set_text_alignment(alignment)
+    }
+}
+
+/// A write-only property for the alignment of a Label's or RawLabel's text under the UI language's direction,
+/// so that switching to a right to left language at runtime moves Start aligned text to the right.
+/// This is for text whose content doesn't tell the text system its direction, eg numbers or mixed scripts.
+pub struct DirectedAlignmentProperty<W> {
     phantom_w: PhantomData<W>,
 }
 
-impl<T, W> ScrollToProperty<T, W> {
-    /// Create a Scroll To property for the specified axis.
-    pub fn new(direction: Axis) -> Self {
-        ScrollToProperty {
-            direction,
-            phantom_t: Default::default(),
+impl<W> Default for DirectedAlignmentProperty<W> {
+    fn default() -> Self {
+        DirectedAlignmentProperty {
             phantom_w: Default::default(),
         }
     }
 }
 
-impl<T, W: Widget<T>> BindableProperty for ScrollToProperty<T, W> {
-    type Controlled = Scroll<T, W>;
-    type Value = f64;
+impl<W: TextAlignable> BindableProperty for DirectedAlignmentProperty<W> {
+    type Controlled = W;
+    type Value = DirectedAlignment;
     type Change = ();
 
     fn write_prop(
         &self,
         controlled: &mut Self::Controlled,
         ctx: &mut UpdateCtx,
-        position: &Self::Value,
+        field_val: &Self::Value,
         _env: &Env,
     ) {
-        controlled.scroll_to_on_axis(self.direction, *position);
-        ctx.request_paint()
+        controlled.set_alignment(field_val.resolve());
+        ctx.request_layout()
     }
 
     fn append_changes(
         &self,
-        controlled: &Self::Controlled,
-        field_val: &Self::Value,
-        change: &mut Option<Self::Change>,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
         _env: &Env,
     ) {
-        if !controlled.offset_for_axis(self.direction).same(field_val) {
-            *change = Some(())
-        }
     }
 
     fn update_data_from_change(
         &self,
-        controlled: &Self::Controlled,
+        _controlled: &Self::Controlled,
         _ctx: &EventCtx,
-        field: &mut Self::Value,
+        _field: &mut Self::Value,
         _change: Self::Change,
         _env: &Env,
     ) {
-        *field = controlled.offset_for_axis(self.direction)
     }
 }
 
+/// All of the style of a Label's text, so that a theme switch can restyle a label from one field
+/// with one binding, rather than five.
+#[derive(Debug, Clone, Data)]
+pub struct LabelStyle {
+    // KeyOrValue is only Data on druid master
+    /// The colour of the text
+    #[data(same_fn = "PartialEq::eq")]
+    pub color: KeyOrValue<Color>,
+    /// The size of the text
+    #[data(same_fn = "PartialEq::eq")]
+    pub size: KeyOrValue<f64>,
+    /// The font of the text
+    #[data(same_fn = "PartialEq::eq")]
+    pub font: KeyOrValue<FontDescriptor>,
+    /// How the text is aligned
+    #[data(same_fn = "PartialEq::eq")]
+    pub alignment: TextAlignment,
+    /// How lines that don't fit are broken
+    pub line_break: LineBreaking,
+}
+
+impl Default for LabelStyle {
+    /// The style a Label starts out with
+    fn default() -> Self {
+        LabelStyle {
+            color: compat::TEXT_COLOR.into(),
+            size: theme::TEXT_SIZE_NORMAL.into(),
+            font: theme::UI_FONT.into(),
+            alignment: TextAlignment::Start,
+            line_break: LineBreaking::Overflow,
+        }
+    }
+}
+
+/// A write-only property applying a whole LabelStyle to a Label.
+pub struct LabelStyleProperty<T> {
+    phantom_t: PhantomData<T>,
+}
+
+impl<T> Default for LabelStyleProperty<T> {
+    fn default() -> Self {
+        LabelStyleProperty {
+            phantom_t: Default::default(),
+        }
+    }
+}
+
+impl<T: Data> BindableProperty for LabelStyleProperty<T> {
+    type Controlled = Label<T>;
+    type Value = LabelStyle;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_text_color(field_val.color.clone());
+        controlled.set_text_size(field_val.size.clone());
+        controlled.set_font(field_val.font.clone());
+        controlled.set_text_alignment(field_val.alignment);
+        controlled.set_line_break_mode(field_val.line_break);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
@@ -0,0 +1,823 @@
+use crate::bindable_access::*;
+use crate::binding::*;
+use crate::fallible::{BindingError, TryBindableProperty};
+use druid::scroll_component::ScrollbarsEnabled;
+use druid::widget::prelude::*;
+use druid::widget::{Axis, ClipBox, Scroll};
+use druid::{Data, Lens, Point, Vec2};
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+impl<T, W> BindableAccess for Scroll<T, W> {
+    bindable_self_body!();
+}
+
+/// A bindable property to allow scroll offsets to be linked to app data.
+/// Useful within composite components with linked scroll areas (eg tables)
+pub struct ScrollToProperty<T, W> {
+    direction: Axis, // We have no public direction/axis type, but two private ones. Sigh.
+    keep_relative: bool,
+    /// The scroll limit and offset at the last sync, to detect the content resizing
+    seen: Cell<Option<(f64, f64)>>,
+    /// An offset preserving the position through a resize, until it has been written back to the widget
+    kept: Cell<Option<f64>>,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> ScrollToProperty<T, W> {
+    /// Create a Scroll To property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        ScrollToProperty {
+            direction,
+            keep_relative: false,
+            seen: Cell::new(None),
+            kept: Cell::new(None),
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+
+    /// When the scrolled content changes size, eg on a font change, rewrite the bound offset
+    /// so the position stays at the same fraction of the scrollable distance, rather than the same distance
+    /// from the start. The new offset is then written back to the Scroll like any other change to the data.
+    pub fn keep_relative(mut self) -> Self {
+        self.keep_relative = true;
+        self
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollToProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = f64;
+    type Change = f64;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        position: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.scroll_to_on_axis(ctx, self.direction, *position);
+        if self.keep_relative {
+            self.kept.set(None);
+            self.seen.set(Some((
+                scroll_limit(controlled, self.direction),
+                controlled.offset_for_axis(self.direction),
+            )));
+        }
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        let offset = controlled.offset_for_axis(self.direction);
+        if self.keep_relative {
+            let limit = scroll_limit(controlled, self.direction);
+            if let Some((seen_limit, seen_offset)) = self.seen.get() {
+                if !seen_limit.same(&limit) && seen_limit > 0. {
+                    self.kept
+                        .set(Some((seen_offset / seen_limit * limit).min(limit)));
+                }
+            }
+            if let Some(kept) = self.kept.get() {
+                // Hold the kept offset until it reaches the widget, as the widget's own offset is stale
+                self.seen.set(Some((limit, kept)));
+                if !kept.same(field_val) {
+                    *change = Some(kept)
+                }
+                return;
+            }
+            self.seen.set(Some((limit, offset)));
+        }
+        if !offset.same(field_val) {
+            *change = Some(offset)
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = change
+    }
+}
+
+/// The pair of bindings scroll_offsets makes, one per axis of a Scroll. A pair of bindings is itself a binding.
+pub type ScrollOffsetsBinding<S, T, W, LX, LY> = (
+    LensPropBinding<S, Scroll<T, W>, f64, LX, ScrollToProperty<T, W>>,
+    LensPropBinding<S, Scroll<T, W>, f64, LY, ScrollToProperty<T, W>>,
+);
+
+/// Bind both scroll offsets of a Scroll at once, the horizontal to lens_x and the vertical to lens_y.
+/// Each axis is written with scroll_to_on_axis, so a data change to one axis never moves the other,
+/// and an offset that already agrees with the data is not echoed back to it.
+pub fn scroll_offsets<S, T, W: Widget<T>, LX: Lens<S, f64>, LY: Lens<S, f64>>(
+    lens_x: LX,
+    lens_y: LY,
+) -> ScrollOffsetsBinding<S, T, W, LX, LY> {
+    (
+        LensPropBinding::new(lens_x, ScrollToProperty::new(Axis::Horizontal)),
+        LensPropBinding::new(lens_y, ScrollToProperty::new(Axis::Vertical)),
+    )
+}
+
+impl<T, W: Widget<T>> TryBindableProperty for ScrollToProperty<T, W> {
+    fn try_write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        position: &Self::Value,
+        env: &Env,
+    ) -> Result<(), BindingError> {
+        if position.is_finite() {
+            self.write_prop(controlled, ctx, position, env);
+            Ok(())
+        } else {
+            Err(BindingError::new(
+                "ScrollToProperty",
+                format!("{} is not a scroll offset", position),
+            ))
+        }
+    }
+
+    fn clamp_value(&self, controlled: &Self::Controlled, _position: &Self::Value) -> Option<f64> {
+        Some(controlled.offset_for_axis(self.direction))
+    }
+}
+
+fn on_axis(axis: Axis, size: Size) -> f64 {
+    match axis {
+        Axis::Horizontal => size.width,
+        Axis::Vertical => size.height,
+    }
+}
+
+/// The furthest a Scroll can be scrolled on an axis
+fn scroll_limit<T, W: Widget<T>>(scroll: &Scroll<T, W>, axis: Axis) -> f64 {
+    (on_axis(axis, scroll.child_size()) - on_axis(axis, scroll.viewport_rect().size())).max(0.)
+}
+
+/// A bindable property linking the scroll position on an axis, as a fraction of the scrollable distance, to app data.
+/// 0 is the start and 1 the end. Useful for syncing scroll areas whose content differs in size.
+///
+/// Positions are compared within a tolerance rather than exactly, so fractions that don't survive the
+/// round trip through pixel offsets unchanged don't cause changes to echo back into the data.
+pub struct AxisFractionProperty<T, W> {
+    direction: Axis,
+    tolerance: f64,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> AxisFractionProperty<T, W> {
+    /// The tolerance used unless another is given with with_tolerance
+    pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
+    /// The vertical scroll fraction
+    pub const VERTICAL: Self = AxisFractionProperty::new(Axis::Vertical);
+    /// The horizontal scroll fraction
+    pub const HORIZONTAL: Self = AxisFractionProperty::new(Axis::Horizontal);
+
+    /// Create a scroll fraction property for the specified axis.
+    pub const fn new(direction: Axis) -> Self {
+        AxisFractionProperty {
+            direction,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            phantom_t: PhantomData,
+            phantom_w: PhantomData,
+        }
+    }
+
+    /// Create a vertical scroll fraction property
+    pub const fn vertical() -> Self {
+        Self::new(Axis::Vertical)
+    }
+
+    /// Create a horizontal scroll fraction property
+    pub const fn horizontal() -> Self {
+        Self::new(Axis::Horizontal)
+    }
+
+    /// Set how far apart the widget and data fractions must be to count as a change.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+impl<T, W: Widget<T>> AxisFractionProperty<T, W> {
+    fn fraction(&self, scroll: &Scroll<T, W>) -> f64 {
+        let limit = scroll_limit(scroll, self.direction);
+        if limit > 0. {
+            scroll.offset_for_axis(self.direction) / limit
+        } else {
+            0.
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for AxisFractionProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        fraction: &Self::Value,
+        _env: &Env,
+    ) {
+        let limit = scroll_limit(controlled, self.direction);
+        controlled.scroll_to_on_axis(ctx, self.direction, fraction * limit);
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if (self.fraction(controlled) - field_val).abs() > self.tolerance {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = self.fraction(controlled)
+    }
+}
+
+/// A bindable property linking the scroll position on both axes, as a pair of fractions, to app data.
+/// x is the horizontal fraction and y the vertical one. Useful for syncing 2D viewports like tables.
+pub struct AxisFractionsProperty<T, W> {
+    horizontal: AxisFractionProperty<T, W>,
+    vertical: AxisFractionProperty<T, W>,
+}
+
+impl<T, W> AxisFractionsProperty<T, W> {
+    /// Set how far apart the widget and data fractions on either axis must be to count as a change.
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        AxisFractionsProperty {
+            horizontal: self.horizontal.with_tolerance(tolerance),
+            vertical: self.vertical.with_tolerance(tolerance),
+        }
+    }
+}
+
+impl<T, W> AxisFractionProperty<T, W> {
+    /// Create a property for the scroll fractions on both axes at once
+    pub const fn both() -> AxisFractionsProperty<T, W> {
+        AxisFractionsProperty {
+            horizontal: Self::horizontal(),
+            vertical: Self::vertical(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for AxisFractionsProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = Vec2;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        fractions: &Self::Value,
+        env: &Env,
+    ) {
+        self.horizontal
+            .write_prop(controlled, ctx, &fractions.x, env);
+        self.vertical.write_prop(controlled, ctx, &fractions.y, env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.horizontal
+            .append_changes(controlled, &field_val.x, change, env);
+        self.vertical
+            .append_changes(controlled, &field_val.y, change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = Vec2::new(
+            self.horizontal.fraction(controlled),
+            self.vertical.fraction(controlled),
+        )
+    }
+}
+
+/// A read-only property exposing the size of a Scroll's content.
+/// Useful for drawing custom scrollbars or minimaps elsewhere in the tree.
+pub struct ScrollContentSizeProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ScrollContentSizeProperty<T, W> {
+    fn default() -> Self {
+        ScrollContentSizeProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollContentSizeProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = Size;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.child_size() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.child_size()
+    }
+}
+
+/// A read-only property exposing the furthest a Scroll can be scrolled on an axis,
+/// ie the content extent less the viewport extent.
+pub struct ScrollLimitProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> ScrollLimitProperty<T, W> {
+    /// Create a scroll limit property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        ScrollLimitProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollLimitProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !scroll_limit(controlled, self.direction).same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = scroll_limit(controlled, self.direction)
+    }
+}
+
+/// A read-only property exposing the origin of a Scroll's viewport in content coordinates.
+pub struct ScrollViewportOriginProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ScrollViewportOriginProperty<T, W> {
+    fn default() -> Self {
+        ScrollViewportOriginProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollViewportOriginProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = Point;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.viewport_rect().origin() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.viewport_rect().origin()
+    }
+}
+
+/// Whether a Scroll is at either end of its scrollable range on one axis.
+/// Content no bigger than the viewport is at both ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Data)]
+pub struct AxisEdges {
+    /// Scrolled all the way to the start (top or left)
+    pub at_start: bool,
+    /// Scrolled all the way to the end (bottom or right)
+    pub at_end: bool,
+}
+
+/// A read-only property reporting whether a Scroll is at the start or end of an axis.
+/// Useful for loading more content when scrolled to the bottom, or
+/// showing a shadow under a toolbar when content is scrolled under it.
+pub struct ScrollEdgeProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> ScrollEdgeProperty<T, W> {
+    /// Create a scroll edge property for the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        ScrollEdgeProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> ScrollEdgeProperty<T, W> {
+    fn edges(&self, scroll: &Scroll<T, W>) -> AxisEdges {
+        let offset = scroll.offset_for_axis(self.direction);
+        AxisEdges {
+            at_start: offset <= 0.,
+            at_end: offset >= scroll_limit(scroll, self.direction),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollEdgeProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = AxisEdges;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if self.edges(controlled) != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = self.edges(controlled)
+    }
+}
+
+/// A write-only property controlling which scrollbars a Scroll shows.
+/// Druid fades the enabled scrollbars in and out itself, so this covers on (for some set of axes) and off.
+/// Scrolling by wheel or trackpad is still possible with the scrollbars disabled.
+pub struct ScrollbarsEnabledProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ScrollbarsEnabledProperty<T, W> {
+    fn default() -> Self {
+        ScrollbarsEnabledProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollbarsEnabledProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = ScrollbarsEnabled;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        enabled: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_enabled_scrollbars(*enabled);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
+
+/// A write-only property enabling or disabling scrolling on one axis of a Scroll, as Scroll::vertical
+/// and Scroll::horizontal do when it is built. Once disabled, the axis has no scrollbar and can't be scrolled,
+/// and the content is sized to fit the viewport along it.
+pub struct ScrollbarAxisEnabledProperty<T, W> {
+    direction: Axis,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> ScrollbarAxisEnabledProperty<T, W> {
+    /// Create a property enabling scrolling on the specified axis.
+    pub fn new(direction: Axis) -> Self {
+        ScrollbarAxisEnabledProperty {
+            direction,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ScrollbarAxisEnabledProperty<T, W> {
+    type Controlled = Scroll<T, W>;
+    type Value = bool;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        enabled: &Self::Value,
+        _env: &Env,
+    ) {
+        match self.direction {
+            Axis::Horizontal => controlled.set_horizontal_scroll_enabled(*enabled),
+            Axis::Vertical => controlled.set_vertical_scroll_enabled(*enabled),
+        }
+        // The content is constrained to the viewport on axes that don't scroll, so it needs laying out again
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
+
+impl<T, W> BindableAccess for ClipBox<T, W> {
+    bindable_self_body!();
+}
+
+/// A bindable property for the origin of a ClipBox's viewport, in its child's coordinates.
+pub struct ClipBoxViewportOriginProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ClipBoxViewportOriginProperty<T, W> {
+    fn default() -> Self {
+        ClipBoxViewportOriginProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ClipBoxViewportOriginProperty<T, W> {
+    type Controlled = ClipBox<T, W>;
+    type Value = Point;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        if controlled.with_port(ctx, |_, port| {
+            port.pan_to(*field_val);
+        }) {
+            ctx.request_paint()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.viewport_origin() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.viewport_origin()
+    }
+}
+
+/// A property panning a ClipBox by the delta written to it, for pan and zoom canvases
+/// that move the view relative to where it is.
+/// Once a delta has been applied the bound field is reset to zero, so the same movement can be written again.
+pub struct ClipBoxPanByProperty<T, W> {
+    consumed: Cell<bool>,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ClipBoxPanByProperty<T, W> {
+    fn default() -> Self {
+        ClipBoxPanByProperty {
+            consumed: Cell::new(false),
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for ClipBoxPanByProperty<T, W> {
+    type Controlled = ClipBox<T, W>;
+    type Value = Vec2;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        delta: &Self::Value,
+        _env: &Env,
+    ) {
+        if *delta != Vec2::ZERO {
+            if controlled.pan_by(ctx, *delta) {
+                ctx.request_paint()
+            }
+            self.consumed.set(true)
+        }
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        delta: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        // Only reset deltas that have been applied, not ones still on their way to the widget
+        if self.consumed.get() && *delta != Vec2::ZERO {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        delta: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        self.consumed.set(false);
+        *delta = Vec2::ZERO
+    }
+}
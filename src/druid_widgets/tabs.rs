@@ -0,0 +1,372 @@
+use crate::bindable_access::*;
+use crate::binding::*;
+use crate::fallible::{BindingError, TryBindableProperty};
+use druid::widget::prelude::*;
+use druid::widget::{Tabs, TabsPolicy};
+use druid::Data;
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+impl<TP: TabsPolicy> BindableAccess for Tabs<TP> {
+    bindable_self_body!();
+}
+
+/// A property for the index of the selected tab of a Tabs, following the user's clicks on the tab bar.
+///
+/// Tabs keeps its labels to itself, so to show the selected tab's label elsewhere, eg in a window title,
+/// bind this index and derive the label from it in the data, where the tabs come from.
+///
+/// Tabs can't be asked how many tabs it has, so build the property with the count to keep indices
+/// from the data within the tabs. Indices past the last tab are then written as the last tab,
+/// or rejected when bound with try_bind.
+/// The default property doesn't know the count, and writes indices unchecked.
+pub struct TabsIndexProperty<TP> {
+    tab_count: Option<usize>,
+    phantom_tp: PhantomData<TP>,
+}
+
+impl<TP> TabsIndexProperty<TP> {
+    /// Create an index property for a Tabs showing tab_count tabs
+    pub fn new(tab_count: usize) -> Self {
+        TabsIndexProperty {
+            tab_count: Some(tab_count),
+            phantom_tp: Default::default(),
+        }
+    }
+
+    /// The index of the last tab, if the tab count is known and there are any
+    fn last_index(&self) -> Option<usize> {
+        self.tab_count.and_then(|count| count.checked_sub(1))
+    }
+}
+
+impl<TP> Default for TabsIndexProperty<TP> {
+    fn default() -> Self {
+        TabsIndexProperty {
+            tab_count: None,
+            phantom_tp: Default::default(),
+        }
+    }
+}
+
+impl<TP: TabsPolicy> BindableProperty for TabsIndexProperty<TP> {
+    type Controlled = Tabs<TP>;
+    type Value = usize;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        index: &Self::Value,
+        _env: &Env,
+    ) {
+        let index = match self.last_index() {
+            Some(last) => (*index).min(last),
+            None => *index,
+        };
+        if controlled.tab_index() != index {
+            controlled.set_tab_index(index);
+            ctx.request_layout()
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        index: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.tab_index() != *index {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        index: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *index = controlled.tab_index()
+    }
+}
+
+impl<TP: TabsPolicy> TryBindableProperty for TabsIndexProperty<TP> {
+    fn try_write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        index: &Self::Value,
+        env: &Env,
+    ) -> Result<(), BindingError> {
+        match self.tab_count {
+            Some(count) if *index >= count => Err(BindingError::new(
+                "TabsIndexProperty",
+                format!("there is no tab {} of {}", index, count),
+            )),
+            _ => {
+                self.write_prop(controlled, ctx, index, env);
+                Ok(())
+            }
+        }
+    }
+
+    fn clamp_value(&self, _controlled: &Self::Controlled, _index: &Self::Value) -> Option<usize> {
+        self.last_index()
+    }
+}
+
+/// A move from the selected tab of a Tabs to another, eg from a keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum TabStep {
+    /// The tab after the selected one, staying on the last tab
+    Next,
+    /// The tab before the selected one, staying on the first tab
+    Prev,
+    /// The first tab
+    First,
+    /// The last tab
+    Last,
+}
+
+impl TabStep {
+    /// The index this step selects from index, among tab_count tabs
+    pub fn from_index(self, index: usize, tab_count: usize) -> usize {
+        let last = tab_count.saturating_sub(1);
+        match self {
+            TabStep::Next => (index + 1).min(last),
+            TabStep::Prev => index.saturating_sub(1).min(last),
+            TabStep::First => 0,
+            TabStep::Last => last,
+        }
+    }
+}
+
+/// A write-only property moving the selection of a Tabs by the step written to it,
+/// so keyboard shortcuts handled in app logic can drive the tabs without computing indices.
+/// Once a step has been taken the bound field is reset to None, so the same step can be written again.
+///
+/// Tabs can't be asked how many tabs it has, so the property is built with the count, for Next and Last.
+pub struct TabsTriggerProperty<TP> {
+    tab_count: usize,
+    consumed: Cell<bool>,
+    phantom_tp: PhantomData<TP>,
+}
+
+impl<TP> TabsTriggerProperty<TP> {
+    /// Create a trigger property for a Tabs showing tab_count tabs
+    pub fn new(tab_count: usize) -> Self {
+        TabsTriggerProperty {
+            tab_count,
+            consumed: Cell::new(false),
+            phantom_tp: Default::default(),
+        }
+    }
+}
+
+impl<TP: TabsPolicy> BindableProperty for TabsTriggerProperty<TP> {
+    type Controlled = Tabs<TP>;
+    type Value = Option<TabStep>;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        step: &Self::Value,
+        _env: &Env,
+    ) {
+        if let Some(step) = step {
+            let index = step.from_index(controlled.tab_index(), self.tab_count);
+            if index != controlled.tab_index() {
+                controlled.set_tab_index(index);
+                ctx.request_layout()
+            }
+            self.consumed.set(true)
+        }
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        step: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        // Only reset steps that have been taken, not ones still on their way to the widget
+        if self.consumed.get() && step.is_some() {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        step: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        self.consumed.set(false);
+        *step = None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fallible::RejectionPolicy;
+    use crate::testing::PropertyHarness;
+    use druid::tests::harness::Harness;
+    use druid::widget::{Controller, ControllerHost, Label};
+    use druid::{Lens, Selector};
+
+    fn three_tabs<T: Data>() -> Tabs<impl TabsPolicy<Input = T>> {
+        Tabs::new()
+            .with_tab("One", Label::<T>::new("One"))
+            .with_tab("Two", Label::new("Two"))
+            .with_tab("Three", Label::new("Three"))
+    }
+
+    #[derive(Clone, Data, Lens)]
+    struct Selection {
+        index: usize,
+        #[data(same_fn = "PartialEq::eq")]
+        error: Option<BindingError>,
+    }
+
+    /// Select a tab through the data, as app logic would
+    const SELECT: Selector<usize> = Selector::new("druid-bindings.test.select");
+
+    struct Chooser;
+
+    impl<W: Widget<Selection>> Controller<Selection, W> for Chooser {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut Selection,
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(c) if c.is(SELECT) => data.index = *c.get_unchecked(SELECT),
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    /// The data after selecting index on the second of three tabs, bound with policy
+    fn selected(index: usize, policy: RejectionPolicy) -> Selection {
+        let host = BindingHost::<Selection, Selection, _, _, _>::new(
+            three_tabs().with_tab_index(1),
+            Selection::index
+                .try_bind(TabsIndexProperty::new(3), policy)
+                .errors_to(Selection::error),
+        );
+        let root = ControllerHost::new(host, Chooser);
+        let data = Selection {
+            index: 1,
+            error: None,
+        };
+        let mut selection = data.clone();
+        Harness::create_simple(data, root, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.submit_command(SELECT.with(index));
+            harness.submit_command(Selector::NOOP);
+            selection = harness.data().clone();
+        });
+        selection
+    }
+
+    #[test]
+    fn steps_stay_within_the_tabs() {
+        assert_eq!(TabStep::Next.from_index(1, 3), 2);
+        assert_eq!(TabStep::Next.from_index(2, 3), 2);
+        assert_eq!(TabStep::Prev.from_index(1, 3), 0);
+        assert_eq!(TabStep::Prev.from_index(0, 3), 0);
+        assert_eq!(TabStep::First.from_index(2, 3), 0);
+        assert_eq!(TabStep::Last.from_index(0, 3), 2);
+        assert_eq!(TabStep::Next.from_index(0, 0), 0);
+    }
+
+    #[test]
+    fn the_index_follows_the_selected_tab() {
+        let tabs = Tabs::new()
+            .with_tab("One", Label::<()>::new("One"))
+            .with_tab("Two", Label::<()>::new("Two"));
+        PropertyHarness::run(TabsIndexProperty::default(), tabs, 1, |harness| {
+            assert_eq!(harness.controlled().tab_index(), 1);
+            harness.controlled_mut().set_tab_index(0);
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 0);
+        });
+    }
+
+    #[test]
+    fn indices_past_the_last_tab_select_the_last() {
+        PropertyHarness::run(
+            TabsIndexProperty::new(3),
+            three_tabs::<usize>(),
+            0,
+            |harness| {
+                harness.write(7);
+                assert_eq!(harness.controlled().tab_index(), 2);
+            },
+        );
+    }
+
+    #[test]
+    fn indices_of_tabs_that_exist_are_accepted() {
+        for policy in [
+            RejectionPolicy::Ignore,
+            RejectionPolicy::Clamp,
+            RejectionPolicy::Log,
+        ] {
+            let selection = selected(2, policy);
+            assert_eq!(selection.index, 2);
+            assert_eq!(selection.error, None);
+        }
+    }
+
+    #[test]
+    fn indices_past_the_last_tab_are_rejected() {
+        let rejected = Some(BindingError::new(
+            "TabsIndexProperty",
+            "there is no tab 7 of 3",
+        ));
+        let ignored = selected(7, RejectionPolicy::Ignore);
+        assert_eq!(ignored.index, 1);
+        assert_eq!(ignored.error, rejected);
+        let clamped = selected(7, RejectionPolicy::Clamp);
+        assert_eq!(clamped.index, 2);
+        assert_eq!(clamped.error, rejected);
+        let logged = selected(7, RejectionPolicy::Log);
+        assert_eq!(logged.index, 1);
+        assert_eq!(logged.error, rejected);
+    }
+
+    #[test]
+    fn triggers_step_the_tabs_and_reset() {
+        PropertyHarness::run(
+            TabsTriggerProperty::new(3),
+            three_tabs::<Option<TabStep>>(),
+            None,
+            |harness| {
+                harness.write(Some(TabStep::Next));
+                assert_eq!(harness.controlled().tab_index(), 1);
+                assert!(harness.read_back());
+                assert_eq!(*harness.field(), None);
+                harness.write(Some(TabStep::Last));
+                assert_eq!(harness.controlled().tab_index(), 2);
+                harness.write(Some(TabStep::Next));
+                assert_eq!(harness.controlled().tab_index(), 2);
+            },
+        );
+    }
+}
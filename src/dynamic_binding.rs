@@ -0,0 +1,522 @@
+use crate::binding::Binding;
+use druid::{Env, EventCtx, Selector, SingleUse, UpdateCtx};
+use std::any::Any;
+
+/// An object safe form of Binding, with its Change boxed, so that bindings of different types can be
+/// held together and chosen at runtime. Every Binding with a 'static Change is an AnyBinding.
+pub trait AnyBinding<T, Controlled> {
+    /// As Binding::apply_data_to_controlled
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    );
+
+    /// As Binding::append_change_required, with the change boxed
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Box<dyn Any>>,
+        env: &Env,
+    );
+
+    /// As Binding::apply_change_to_data, with the change boxed
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Box<dyn Any>,
+        ctx: &mut EventCtx,
+        env: &Env,
+    );
+
+    /// As Binding::priority
+    fn priority(&self) -> i32;
+
+    /// As Binding::env_dependent
+    fn env_dependent(&self) -> bool;
+
+    /// As Binding::apply_env_to_controlled
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    );
+
+    /// As Binding::wants_initial_data
+    fn wants_initial_data(&self) -> bool;
+
+    /// As Binding::apply_initial_data_to_controlled
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    );
+
+    /// As Binding::describe
+    fn describe(&self) -> String;
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> AnyBinding<T, Controlled> for B
+where
+    B::Change: 'static,
+{
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        Binding::apply_data_to_controlled(self, data, controlled, ctx, env)
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Box<dyn Any>>,
+        env: &Env,
+    ) {
+        let mut typed = change
+            .take()
+            .and_then(|boxed| boxed.downcast::<B::Change>().ok())
+            .map(|boxed| *boxed);
+        Binding::append_change_required(self, controlled, data, &mut typed, env);
+        *change = typed.map(|typed| Box::new(typed) as Box<dyn Any>);
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Box<dyn Any>,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        if let Ok(change) = change.downcast::<B::Change>() {
+            Binding::apply_change_to_data(self, controlled, data, *change, ctx, env)
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        Binding::priority(self)
+    }
+
+    fn env_dependent(&self) -> bool {
+        Binding::env_dependent(self)
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        Binding::apply_env_to_controlled(self, data, controlled, ctx, env)
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        Binding::wants_initial_data(self)
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        Binding::apply_initial_data_to_controlled(self, data, controlled, ctx, env)
+    }
+
+    fn describe(&self) -> String {
+        Binding::describe(self)
+    }
+}
+
+/// A binding of any type with a 'static Change, behind a box, so that bindings can be kept in collections
+/// and chosen at runtime without their types growing with each combination. Its Change is boxed too.
+/// Make one with BindingExt::boxed.
+pub struct BoxedBinding<T, Controlled> {
+    binding: Box<dyn AnyBinding<T, Controlled>>,
+}
+
+impl<T, Controlled> BoxedBinding<T, Controlled> {
+    /// Box up binding
+    pub fn new<B: Binding<T, Controlled> + 'static>(binding: B) -> Self
+    where
+        B::Change: 'static,
+    {
+        BoxedBinding {
+            binding: Box::new(binding),
+        }
+    }
+}
+
+impl<T, Controlled> Binding<T, Controlled> for BoxedBinding<T, Controlled> {
+    type Change = Box<dyn Any>;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.binding
+            .append_change_required(controlled, data, change, env)
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_change_to_data(controlled, data, change, ctx, env)
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_initial_data_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn describe(&self) -> String {
+        self.binding.describe()
+    }
+}
+
+/// A binding wrapped up to be sent to a BindingHost with ADD_BINDING. The host ignores it
+/// unless its binding is a DynamicBindings of the same data and controlled types.
+pub struct NamedBinding {
+    name: String,
+    binding: Box<dyn Any>,
+}
+
+impl NamedBinding {
+    /// Wrap up binding, to be removed later with REMOVE_BINDING and the same name
+    pub fn new<T: 'static, Controlled: 'static, B: Binding<T, Controlled> + 'static>(
+        name: impl Into<String>,
+        binding: B,
+    ) -> Self
+    where
+        B::Change: 'static,
+    {
+        NamedBinding {
+            name: name.into(),
+            binding: Box::new(BoxedBinding::new(binding)),
+        }
+    }
+}
+
+/// Sent to a BindingHost whose binding is a DynamicBindings to add a binding to it.
+/// Target the host by its id (see BindingHost::with_id), as the binding can only be taken once.
+/// The new binding, and those already there, are written from the data at the next update.
+pub const ADD_BINDING: Selector<SingleUse<NamedBinding>> =
+    Selector::new("druid-bindings.add-binding");
+
+/// Sent to a BindingHost whose binding is a DynamicBindings to remove the binding with this name.
+pub const REMOVE_BINDING: Selector<String> = Selector::new("druid-bindings.remove-binding");
+
+/// A binding made of others that can be added and removed while its host is in the widget tree,
+/// with ADD_BINDING and REMOVE_BINDING, for when the properties to bind are only known at runtime.
+/// It must be the binding of the host itself, not part of a combination, for the host to pass those on.
+///
+/// Bindings are applied in the order they were added, except that higher priorities go first.
+/// Pending changes are kept by name, so a removed binding's changes are dropped.
+pub struct DynamicBindings<T, Controlled> {
+    bindings: Vec<(String, BoxedBinding<T, Controlled>)>,
+}
+
+impl<T, Controlled> Default for DynamicBindings<T, Controlled> {
+    fn default() -> Self {
+        DynamicBindings {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<T, Controlled> DynamicBindings<T, Controlled> {
+    /// Start with no bindings
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Builder-style method to start with a binding
+    pub fn with_binding<B: Binding<T, Controlled> + 'static>(
+        mut self,
+        name: impl Into<String>,
+        binding: B,
+    ) -> Self
+    where
+        B::Change: 'static,
+    {
+        self.add(name, BoxedBinding::new(binding));
+        self
+    }
+
+    /// Add a binding, replacing any with the same name
+    pub fn add(&mut self, name: impl Into<String>, binding: BoxedBinding<T, Controlled>) {
+        let name = name.into();
+        self.remove(&name);
+        let priority = Binding::priority(&binding);
+        let idx = self
+            .bindings
+            .iter()
+            .position(|(_, existing)| Binding::priority(existing) < priority)
+            .unwrap_or(self.bindings.len());
+        self.bindings.insert(idx, (name, binding));
+    }
+
+    /// Remove the binding with this name, returning whether there was one
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.bindings.len();
+        self.bindings.retain(|(existing, _)| existing != name);
+        self.bindings.len() != before
+    }
+
+    /// Whether there is a binding with this name
+    pub fn contains(&self, name: &str) -> bool {
+        self.bindings.iter().any(|(existing, _)| existing == name)
+    }
+}
+
+/// Attaching and detaching bindings sent to a host, without knowing their types.
+/// Implemented by DynamicBindings.
+pub trait AttachBindings {
+    /// Attach a binding sent with ADD_BINDING. Returns false if it is for other types.
+    fn attach(&mut self, named: NamedBinding) -> bool;
+
+    /// Detach the binding with this name, returning whether there was one
+    fn detach(&mut self, name: &str) -> bool;
+}
+
+impl<T: 'static, Controlled: 'static> AttachBindings for DynamicBindings<T, Controlled> {
+    fn attach(&mut self, named: NamedBinding) -> bool {
+        match named.binding.downcast::<BoxedBinding<T, Controlled>>() {
+            Ok(binding) => {
+                self.add(named.name, *binding);
+                true
+            }
+            Err(_) => {
+                log::warn!(
+                    "Binding {} sent to a host with bindings of different types",
+                    named.name
+                );
+                false
+            }
+        }
+    }
+
+    fn detach(&mut self, name: &str) -> bool {
+        self.remove(name)
+    }
+}
+
+impl<T: 'static, Controlled: 'static> Binding<T, Controlled> for DynamicBindings<T, Controlled> {
+    type Change = Vec<(String, Box<dyn Any>)>;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        for (_, binding) in &self.bindings {
+            Binding::apply_data_to_controlled(binding, data, controlled, ctx, env)
+        }
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let mut changes = change.take().unwrap_or_default();
+        for (name, binding) in &self.bindings {
+            let idx = changes.iter().position(|(changed, _)| changed == name);
+            let mut existing = idx.map(|idx| changes.remove(idx).1);
+            Binding::append_change_required(binding, controlled, data, &mut existing, env);
+            if let Some(existing) = existing {
+                changes.push((name.clone(), existing));
+            }
+        }
+        if !changes.is_empty() {
+            *change = Some(changes)
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        mut change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        for (name, binding) in &self.bindings {
+            if let Some(idx) = change.iter().position(|(changed, _)| changed == name) {
+                let (_, pending) = change.remove(idx);
+                Binding::apply_change_to_data(binding, controlled, data, pending, ctx, env)
+            }
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        self.bindings
+            .iter()
+            .map(|(_, binding)| Binding::priority(binding))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.bindings
+            .iter()
+            .any(|(_, binding)| Binding::env_dependent(binding))
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        for (_, binding) in &self.bindings {
+            Binding::apply_env_to_controlled(binding, data, controlled, ctx, env)
+        }
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.bindings
+            .iter()
+            .any(|(_, binding)| Binding::wants_initial_data(binding))
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        for (_, binding) in &self.bindings {
+            Binding::apply_initial_data_to_controlled(binding, data, controlled, ctx, env)
+        }
+    }
+
+    fn attachable_mut(&mut self) -> Option<&mut dyn AttachBindings> {
+        Some(self)
+    }
+
+    fn describe(&self) -> String {
+        let described: Vec<String> = self
+            .bindings
+            .iter()
+            .map(|(name, binding)| format!("'{}': {}", name, Binding::describe(binding)))
+            .collect();
+        format!("DynamicBindings [{}]", described.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binding::{BindingExt, LensPropBinding};
+    use crate::testing::{MockValueProperty, MockWidget};
+    use druid::lens;
+
+    type Bindings = DynamicBindings<i32, MockWidget<i32>>;
+
+    fn add(bindings: &mut Bindings, name: &str, priority: i32) {
+        let binding = LensPropBinding::new(lens::Identity, MockValueProperty::default());
+        bindings.add(name, BoxedBinding::new(binding.with_priority(priority)))
+    }
+
+    fn names(bindings: &Bindings) -> Vec<&str> {
+        bindings
+            .bindings
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn bindings_are_kept_in_priority_then_added_order() {
+        let mut bindings = Bindings::new();
+        add(&mut bindings, "a", 0);
+        add(&mut bindings, "b", 1);
+        add(&mut bindings, "c", 0);
+        add(&mut bindings, "d", 1);
+        add(&mut bindings, "e", -1);
+        assert_eq!(names(&bindings), ["b", "d", "a", "c", "e"]);
+    }
+
+    #[test]
+    fn adding_a_name_again_replaces_its_binding() {
+        let mut bindings = Bindings::new();
+        add(&mut bindings, "a", 0);
+        add(&mut bindings, "b", 0);
+        add(&mut bindings, "a", 2);
+        assert_eq!(names(&bindings), ["a", "b"]);
+        assert!(bindings.remove("a"));
+        assert!(!bindings.contains("a"));
+        assert_eq!(names(&bindings), ["b"]);
+    }
+}
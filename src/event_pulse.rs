@@ -0,0 +1,192 @@
+use crate::binding::BindableProperty;
+use druid::widget::prelude::*;
+use druid::Data;
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+/// Wraps a widget, watching for the events that reach it matching a filter, eg mouse downs or
+/// particular key presses. It doesn't handle them, so the inner widget still gets them.
+///
+/// Bind EventPulseCountProperty to count the events, or EventPulseFlagProperty to raise a flag
+/// that resets itself, so that interactions with widgets that don't otherwise react to them can be
+/// observed through the data.
+pub struct EventPulse<T, W> {
+    inner: W,
+    filter: Box<dyn Fn(&Event) -> bool>,
+    pulses: u64,
+    raised: Cell<bool>,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> EventPulse<T, W> {
+    /// Watch for events matching filter reaching inner
+    pub fn new(inner: W, filter: impl Fn(&Event) -> bool + 'static) -> Self {
+        EventPulse {
+            inner,
+            filter: Box::new(filter),
+            pulses: 0,
+            raised: Cell::new(false),
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The number of matching events so far
+    pub fn pulses(&self) -> u64 {
+        self.pulses
+    }
+
+    /// Whether a matching event has arrived since the flag was last lowered
+    pub fn is_raised(&self) -> bool {
+        self.raised.get()
+    }
+
+    /// Lower the flag
+    pub fn lower(&self) {
+        self.raised.set(false)
+    }
+}
+
+impl<T, W> crate::BindableAccess for EventPulse<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for EventPulse<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if (self.filter)(event) {
+            self.pulses += 1;
+            self.raised.set(true);
+        }
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+/// A read-only property exposing the number of matching events an EventPulse has seen.
+/// The bound field pulses: it increases by one on each matching event.
+pub struct EventPulseCountProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for EventPulseCountProperty<T, W> {
+    fn default() -> Self {
+        EventPulseCountProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for EventPulseCountProperty<T, W> {
+    type Controlled = EventPulse<T, W>;
+    type Value = u64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.pulses().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.pulses()
+    }
+}
+
+/// A read-only property flagging matching events reaching an EventPulse.
+/// The bound field becomes true after a matching event, and resets itself to false the next time the host
+/// checks for changes, so it is true for one update of the widgets observing it.
+/// Events arriving while it is true raise it again once it has reset.
+pub struct EventPulseFlagProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for EventPulseFlagProperty<T, W> {
+    fn default() -> Self {
+        EventPulseFlagProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for EventPulseFlagProperty<T, W> {
+    type Controlled = EventPulse<T, W>;
+    type Value = bool;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        _controlled: &mut Self::Controlled,
+        _ctx: &mut UpdateCtx,
+        _field_val: &Self::Value,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if *field_val || controlled.is_raised() {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        if *field {
+            *field = false
+        } else {
+            *field = true;
+            controlled.lower()
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::binding::{BindableProperty, Binding};
+use crate::diagnostics::short_type_name;
+use druid::{Env, EventCtx, Lens, UpdateCtx};
+use std::marker::PhantomData;
+
+/// Binds a boolean property (eg selected or expanded) of one member of a group to a shared field
+/// holding the key of the member that is currently chosen.
+///
+/// Give each host in the group an ExclusiveGroupBinding with the same lens and its own key.
+/// The property is true exactly when the field holds this member's key. When a widget turns its
+/// property on, the field is set to its key, and the data update turns the property off in every
+/// other member. A member turning itself off leaves the field as it is, so one member stays chosen;
+/// the widget is brought back in line the next time the field is written to it.
+pub struct ExclusiveGroupBinding<T, K, LT: Lens<T, K>, P: BindableProperty<Value = bool>> {
+    lens_from_data: LT,
+    prop_from_controlled: P,
+    key: K,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, K: PartialEq, LT: Lens<T, K>, P: BindableProperty<Value = bool>>
+    ExclusiveGroupBinding<T, K, LT, P>
+{
+    /// Create a binding for the group member with the given key
+    pub fn new(lens_from_data: LT, prop_from_controlled: P, key: K) -> Self {
+        ExclusiveGroupBinding {
+            lens_from_data,
+            prop_from_controlled,
+            key,
+            phantom_t: Default::default(),
+        }
+    }
+
+    fn is_chosen(&self, data: &T) -> bool {
+        self.lens_from_data.with(data, |chosen| *chosen == self.key)
+    }
+}
+
+impl<T, K: PartialEq + Clone, LT: Lens<T, K>, P: BindableProperty<Value = bool>>
+    Binding<T, P::Controlled> for ExclusiveGroupBinding<T, K, LT, P>
+{
+    type Change = P::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut P::Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let chosen = self.is_chosen(data);
+        self.prop_from_controlled
+            .write_prop(controlled, ctx, &chosen, env)
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &P::Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let chosen = self.is_chosen(data);
+        self.prop_from_controlled
+            .append_changes(controlled, &chosen, change, env)
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &P::Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        let mut chosen = self.is_chosen(data);
+        self.prop_from_controlled.update_data_from_change(
+            controlled,
+            ctx,
+            &mut chosen,
+            change,
+            env,
+        );
+        if chosen {
+            let key = &self.key;
+            self.lens_from_data.with_mut(data, |current| {
+                if *current != *key {
+                    *current = key.clone()
+                }
+            })
+        }
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop_from_controlled.env_dependent()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.bind_exclusive({})",
+            short_type_name::<LT>(),
+            self.prop_from_controlled.name()
+        )
+    }
+}
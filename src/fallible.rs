@@ -0,0 +1,437 @@
+use crate::binding::{BindableProperty, Binding};
+use crate::diagnostics::short_type_name;
+use druid::{Env, EventCtx, Lens, UpdateCtx};
+use std::cell::{Cell, RefCell};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// The reason a property refused a value written to it from data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingError {
+    /// A short identification of the property that rejected the value
+    pub property: &'static str,
+    /// Why the value was rejected
+    pub message: String,
+}
+
+impl BindingError {
+    /// Create an error for a rejected write
+    pub fn new(property: &'static str, message: impl Into<String>) -> Self {
+        BindingError {
+            property,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for BindingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rejected value: {}", self.property, self.message)
+    }
+}
+
+impl std::error::Error for BindingError {}
+
+/// A bindable property whose controlled item can reject some values, eg an out of range index.
+/// Data can race ahead of widget state, so bindings to these properties go through try_write_prop,
+/// and the binding's RejectionPolicy decides what happens on failure.
+pub trait TryBindableProperty: BindableProperty {
+    /// Attempt to write the value from a data change to the property on the controlled item.
+    /// On failure the controlled item must be left unchanged.
+    fn try_write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) -> Result<(), BindingError>;
+
+    /// The nearest value to the rejected one that the controlled item will accept, if there is one.
+    /// Used by RejectionPolicy::Clamp.
+    fn clamp_value(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+    ) -> Option<Self::Value> {
+        None
+    }
+}
+
+/// What a fallible binding does when its property rejects a value from data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionPolicy {
+    /// Leave the controlled item as it is
+    Ignore,
+    /// Write the property's clamped value instead. The clamped value then flows back into the data
+    /// as a normal widget change, so the data ends up agreeing with the widget.
+    Clamp,
+    /// Log the error and leave the controlled item as it is
+    Log,
+}
+
+/// A lens that discards errors, for fallible bindings that don't route their errors into data.
+pub struct NoErrorLens;
+
+impl<T> Lens<T, Option<BindingError>> for NoErrorLens {
+    fn with<V, F: FnOnce(&Option<BindingError>) -> V>(&self, _data: &T, f: F) -> V {
+        f(&None)
+    }
+
+    fn with_mut<V, F: FnOnce(&mut Option<BindingError>) -> V>(&self, _data: &mut T, f: F) -> V {
+        f(&mut None)
+    }
+}
+
+/// This binds a lens (LT) on some data (T) to a fallible property (PropC).
+/// Rejected writes are handled according to a RejectionPolicy, and the latest error
+/// (or None once a write succeeds) is written into the data through the error lens (LE).
+/// Writing back the value the widget was left showing doesn't clear the error,
+/// so it stays in the data until a value from the data is accepted.
+pub struct TryLensPropBinding<
+    T,
+    PropValue,
+    LT: Lens<T, PropValue>,
+    PropC: TryBindableProperty<Value = PropValue>,
+    LE: Lens<T, Option<BindingError>>,
+> {
+    lens_from_data: LT,
+    prop_from_controlled: PropC,
+    policy: RejectionPolicy,
+    error_lens: LE,
+    last_error: RefCell<Option<BindingError>>,
+    error_changed: Cell<bool>,
+    /// Whether the field was last set from the widget, so the next write only echoes the widget's value
+    echoed: Cell<bool>,
+    phantom_t: PhantomData<T>,
+    phantom_p: PhantomData<PropValue>,
+}
+
+impl<T, PropValue, LT: Lens<T, PropValue>, PropC: TryBindableProperty<Value = PropValue>>
+    TryLensPropBinding<T, PropValue, LT, PropC, NoErrorLens>
+{
+    /// Create a fallible binding between a lens to data and a property, handling rejections by policy
+    pub fn new(lens_from_data: LT, prop_from_controlled: PropC, policy: RejectionPolicy) -> Self {
+        TryLensPropBinding {
+            lens_from_data,
+            prop_from_controlled,
+            policy,
+            error_lens: NoErrorLens,
+            last_error: RefCell::new(None),
+            error_changed: Cell::new(false),
+            echoed: Cell::new(false),
+            phantom_t: Default::default(),
+            phantom_p: Default::default(),
+        }
+    }
+}
+
+impl<
+        T,
+        PropValue,
+        LT: Lens<T, PropValue>,
+        PropC: TryBindableProperty<Value = PropValue>,
+        LE: Lens<T, Option<BindingError>>,
+    > TryLensPropBinding<T, PropValue, LT, PropC, LE>
+{
+    /// Route the latest rejection into the data through the given lens
+    pub fn errors_to<LE2: Lens<T, Option<BindingError>>>(
+        self,
+        error_lens: LE2,
+    ) -> TryLensPropBinding<T, PropValue, LT, PropC, LE2> {
+        TryLensPropBinding {
+            lens_from_data: self.lens_from_data,
+            prop_from_controlled: self.prop_from_controlled,
+            policy: self.policy,
+            error_lens,
+            last_error: self.last_error,
+            error_changed: self.error_changed,
+            echoed: self.echoed,
+            phantom_t: Default::default(),
+            phantom_p: Default::default(),
+        }
+    }
+
+    fn record(&self, error: Option<BindingError>) {
+        let mut last_error = self.last_error.borrow_mut();
+        if *last_error != error {
+            *last_error = error;
+            self.error_changed.set(true);
+        }
+    }
+}
+
+impl<
+        T,
+        PropValue,
+        LT: Lens<T, PropValue>,
+        PropC: TryBindableProperty<Value = PropValue>,
+        LE: Lens<T, Option<BindingError>>,
+    > Binding<T, PropC::Controlled> for TryLensPropBinding<T, PropValue, LT, PropC, LE>
+{
+    /// The property's own change, and whether the error state needs writing to data
+    type Change = (Option<PropC::Change>, bool);
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut PropC::Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        let prop = &self.prop_from_controlled;
+        let result = self.lens_from_data.with(data, |field_val| {
+            match prop.try_write_prop(controlled, ctx, field_val, env) {
+                Err(error) if self.policy == RejectionPolicy::Clamp => {
+                    if let Some(clamped) = prop.clamp_value(controlled, field_val) {
+                        prop.write_prop(controlled, ctx, &clamped, env)
+                    }
+                    Err(error)
+                }
+                result => result,
+            }
+        });
+        if let (Err(error), RejectionPolicy::Log) = (&result, self.policy) {
+            log::warn!("{}", error);
+        }
+        let echoed = self.echoed.replace(false);
+        if !(echoed && result.is_ok()) {
+            self.record(result.err());
+        }
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &PropC::Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let (prop_change, error_changed) = change.get_or_insert_with(|| (None, false));
+        self.lens_from_data.with(data, |field_val| {
+            self.prop_from_controlled
+                .append_changes(controlled, field_val, prop_change, env)
+        });
+        *error_changed |= self.error_changed.get();
+        if let Some((None, false)) = change {
+            *change = None;
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &PropC::Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        let (prop_change, error_changed) = change;
+        if let Some(prop_change) = prop_change {
+            self.echoed.set(true);
+            self.lens_from_data.with_mut(data, |field| {
+                self.prop_from_controlled.update_data_from_change(
+                    controlled,
+                    ctx,
+                    field,
+                    prop_change,
+                    env,
+                )
+            })
+        }
+        if error_changed {
+            self.error_changed.set(false);
+            let last_error = self.last_error.borrow().clone();
+            self.error_lens.with_mut(data, |error| *error = last_error);
+        }
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop_from_controlled.env_dependent()
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.try_bind({}, {:?})",
+            short_type_name::<LT>(),
+            self.prop_from_controlled.name(),
+            self.policy
+        )
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::binding::{BindingHost, LensBindingExt};
+    use crate::testing::MockWidget;
+    use druid::tests::harness::Harness;
+    use druid::widget::{Controller, ControllerHost};
+    use druid::{Data, Event, Selector, Widget};
+
+    #[derive(Clone, Data, Lens)]
+    struct State {
+        value: i32,
+        #[data(same_fn = "PartialEq::eq")]
+        error: Option<BindingError>,
+    }
+
+    /// Set the bound value, as app logic would
+    const WRITE: Selector<i32> = Selector::new("druid-bindings.test.write");
+
+    struct Writer;
+
+    impl<W: Widget<State>> Controller<State, W> for Writer {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut State,
+            env: &Env,
+        ) {
+            match event {
+                Event::Command(c) if c.is(WRITE) => data.value = *c.get_unchecked(WRITE),
+                _ => child.event(ctx, event, data, env),
+            }
+        }
+    }
+
+    /// The value of a MockWidget, rejecting negative values and clamping them to 0
+    struct NonNegative;
+
+    impl BindableProperty for NonNegative {
+        type Controlled = MockWidget<i32>;
+        type Value = i32;
+        type Change = ();
+
+        fn write_prop(
+            &self,
+            controlled: &mut Self::Controlled,
+            _ctx: &mut UpdateCtx,
+            field_val: &Self::Value,
+            _env: &Env,
+        ) {
+            controlled.value = *field_val
+        }
+
+        fn append_changes(
+            &self,
+            controlled: &Self::Controlled,
+            field_val: &Self::Value,
+            change: &mut Option<Self::Change>,
+            _env: &Env,
+        ) {
+            if controlled.value != *field_val {
+                *change = Some(())
+            }
+        }
+
+        fn update_data_from_change(
+            &self,
+            controlled: &Self::Controlled,
+            _ctx: &EventCtx,
+            field: &mut Self::Value,
+            _change: Self::Change,
+            _env: &Env,
+        ) {
+            *field = controlled.value
+        }
+    }
+
+    impl TryBindableProperty for NonNegative {
+        fn try_write_prop(
+            &self,
+            controlled: &mut Self::Controlled,
+            ctx: &mut UpdateCtx,
+            field_val: &Self::Value,
+            env: &Env,
+        ) -> Result<(), BindingError> {
+            if *field_val < 0 {
+                return Err(BindingError::new("NonNegative", "negative"));
+            }
+            self.write_prop(controlled, ctx, field_val, env);
+            Ok(())
+        }
+
+        fn clamp_value(&self, _controlled: &Self::Controlled, _field_val: &i32) -> Option<i32> {
+            Some(0)
+        }
+    }
+
+    /// The data after writing values in turn to a widget showing 5, bound with policy
+    fn written(values: &[i32], policy: RejectionPolicy) -> State {
+        let host = BindingHost::<State, State, _, _, _>::new(
+            MockWidget::new(5),
+            State::value
+                .try_bind(NonNegative, policy)
+                .errors_to(State::error),
+        );
+        let root = ControllerHost::new(host, Writer);
+        let data = State {
+            value: 5,
+            error: None,
+        };
+        let mut state = data.clone();
+        Harness::create_simple(data, root, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            for value in values {
+                harness.submit_command(WRITE.with(*value));
+                // Apply what the widget was left showing, and the error
+                harness.submit_command(Selector::NOOP);
+            }
+            state = harness.data().clone();
+        });
+        state
+    }
+
+    #[test]
+    fn accepted_values_are_written() {
+        for policy in [
+            RejectionPolicy::Ignore,
+            RejectionPolicy::Clamp,
+            RejectionPolicy::Log,
+        ] {
+            let state = written(&[3], policy);
+            assert_eq!(state.value, 3);
+            assert_eq!(state.error, None);
+        }
+    }
+
+    #[test]
+    fn accepted_values_clear_the_error() {
+        let state = written(&[-1, 3], RejectionPolicy::Ignore);
+        assert_eq!(state.value, 3);
+        assert_eq!(state.error, None);
+    }
+
+    #[test]
+    fn ignored_rejections_leave_the_widget_as_it_was() {
+        let state = written(&[-1], RejectionPolicy::Ignore);
+        assert_eq!(state.value, 5);
+        assert_eq!(
+            state.error,
+            Some(BindingError::new("NonNegative", "negative"))
+        );
+    }
+
+    #[test]
+    fn clamped_rejections_write_the_clamped_value() {
+        let state = written(&[-1], RejectionPolicy::Clamp);
+        assert_eq!(state.value, 0);
+        assert_eq!(
+            state.error,
+            Some(BindingError::new("NonNegative", "negative"))
+        );
+    }
+
+    #[test]
+    fn logged_rejections_leave_the_widget_as_it_was() {
+        let state = written(&[-1], RejectionPolicy::Log);
+        assert_eq!(state.value, 5);
+        assert_eq!(
+            state.error,
+            Some(BindingError::new("NonNegative", "negative"))
+        );
+    }
+}
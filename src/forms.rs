@@ -0,0 +1,133 @@
+use crate::binding::{Binding, WidgetBindingExt};
+use druid::widget::prelude::*;
+use druid::widget::{CrossAxisAlignment, Flex, Label, LabelText};
+use druid::{Color, Lens, WidgetExt};
+use std::marker::PhantomData;
+
+/// Validates a field of the data, writing the error (or None once it is valid) into another field.
+/// The value is checked whenever its host checks for changes, so the error follows edits made through any widget.
+/// This doesn't touch its controlled item, so it can be hosted on any bindable widget, eg the one showing the error.
+pub struct ValidationBinding<T, V, LV, LE, F> {
+    value: LV,
+    error: LE,
+    validate: F,
+    phantom_t: PhantomData<T>,
+    phantom_v: PhantomData<V>,
+}
+
+impl<T, V, LV: Lens<T, V>, LE: Lens<T, Option<String>>, F: Fn(&V) -> Result<(), String>>
+    ValidationBinding<T, V, LV, LE, F>
+{
+    /// Validate the field at value, writing the error to the field at error
+    pub fn new(value: LV, error: LE, validate: F) -> Self {
+        ValidationBinding {
+            value,
+            error,
+            validate,
+            phantom_t: Default::default(),
+            phantom_v: Default::default(),
+        }
+    }
+}
+
+impl<
+        T,
+        V,
+        Controlled,
+        LV: Lens<T, V>,
+        LE: Lens<T, Option<String>>,
+        F: Fn(&V) -> Result<(), String>,
+    > Binding<T, Controlled> for ValidationBinding<T, V, LV, LE, F>
+{
+    type Change = Option<String>;
+
+    fn apply_data_to_controlled(
+        &self,
+        _data: &T,
+        _controlled: &mut Controlled,
+        _ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_change_required(
+        &self,
+        _controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        let result = self.value.with(data, |value| (self.validate)(value).err());
+        self.error.with(data, |error| {
+            if *error != result {
+                *change = Some(result)
+            }
+        })
+    }
+
+    fn apply_change_to_data(
+        &self,
+        _controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        _ctx: &mut EventCtx,
+        _env: &Env,
+    ) {
+        self.error.with_mut(data, |error| *error = change)
+    }
+}
+
+/// A labelled input for one field of a form, with its validation error shown underneath.
+/// The input edits the field at value, and validate's error is written to the field at error
+/// by a ValidationBinding, so the rest of the app can see whether the form is valid, eg to enable a submit button.
+pub struct FormField<T> {
+    inner: Flex<T>,
+}
+
+impl<T: Data> FormField<T> {
+    /// Create a form field from a label, the input widget for the value, and the value's validation
+    pub fn new<V: Data>(
+        label: impl Into<LabelText<T>>,
+        input: impl Widget<V> + 'static,
+        value: impl Lens<T, V> + Clone + 'static,
+        error: impl Lens<T, Option<String>> + Clone + 'static,
+        validate: impl Fn(&V) -> Result<(), String> + 'static,
+    ) -> Self {
+        let error_text = error.clone();
+        let error_label = Label::new(move |data: &T, _env: &Env| {
+            error_text.with(data, |error| error.clone().unwrap_or_default())
+        })
+        .with_text_color(Color::rgb8(0xd0, 0x40, 0x40))
+        .binding(ValidationBinding::new(value.clone(), error, validate));
+
+        let inner = Flex::column()
+            .cross_axis_alignment(CrossAxisAlignment::Start)
+            .with_child(Label::new(label))
+            .with_spacer(2.)
+            .with_child(input.lens(value))
+            .with_child(error_label);
+        FormField { inner }
+    }
+}
+
+impl<T: Data> Widget<T> for FormField<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
@@ -0,0 +1,262 @@
+use crate::binding::ChangeOrigin;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+struct Member {
+    token: u64,
+    priority: i32,
+    initialised: bool,
+}
+
+thread_local! {
+    static GROUPS: RefCell<HashMap<String, Vec<Member>>> = RefCell::new(HashMap::new());
+    static NEXT_TOKEN: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Membership of a BindingHost in a named group of hosts that initialise the same data field
+/// from their widgets, eg several sync scrolled Scrolls bound to one offset.
+///
+/// Hosts join when they are built, so every member is known before any of them initialises,
+/// whatever order they are added to the tree in. Only the members with the highest priority
+/// write the changes their widgets make while the hosts initialise into the data; the others take the value
+/// from the data. Members with equal priorities that both initialise are reported, as the last one wins.
+/// Only members of a group are checked against each other: a host can't tell which field the lenses of
+/// its bindings reach, so hosts initialising the same field without joining a group are not detected.
+/// Members leave their group when they are dropped.
+pub(crate) struct InitGroupMember {
+    group: String,
+    token: u64,
+    priority: i32,
+}
+
+impl InitGroupMember {
+    pub(crate) fn new(group: String, priority: i32) -> Self {
+        let token = NEXT_TOKEN.with(|next| next.replace(next.get() + 1));
+        GROUPS.with(|groups| {
+            groups
+                .borrow_mut()
+                .entry(group.clone())
+                .or_default()
+                .push(Member {
+                    token,
+                    priority,
+                    initialised: false,
+                })
+        });
+        InitGroupMember {
+            group,
+            token,
+            priority,
+        }
+    }
+
+    /// Whether the host should apply a change from its widget, with the given origin, to the data.
+    /// Only changes found while the host initialises, ie with the Init origin, are held back
+    /// for higher priority members: anything later, such as an edit by the user, is applied as usual.
+    pub(crate) fn should_apply(&self, origin: ChangeOrigin) -> bool {
+        if origin != ChangeOrigin::Init {
+            return true;
+        }
+        GROUPS.with(|groups| {
+            let mut groups = groups.borrow_mut();
+            let members = groups.entry(self.group.clone()).or_default();
+            let others = members.iter().filter(|m| m.token != self.token);
+            if others.clone().any(|m| m.priority > self.priority) {
+                return false;
+            }
+            if others
+                .filter(|m| m.priority == self.priority)
+                .any(|m| m.initialised)
+            {
+                log::warn!(
+                    "Binding hosts in init group '{}' with equal priority {} both initialised the data from their widgets, the last one wins. Give them different init priorities to choose one.",
+                    self.group,
+                    self.priority
+                );
+            }
+            if let Some(me) = members.iter_mut().find(|m| m.token == self.token) {
+                me.initialised = true;
+            }
+            true
+        })
+    }
+}
+
+impl Drop for InitGroupMember {
+    fn drop(&mut self) {
+        // The thread local may already be gone if this is dropped during thread teardown
+        let _ = GROUPS.try_with(|groups| {
+            let mut groups = groups.borrow_mut();
+            if let Some(members) = groups.get_mut(&self.group) {
+                members.retain(|m| m.token != self.token);
+                if members.is_empty() {
+                    groups.remove(&self.group);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group_size(group: &str) -> usize {
+        GROUPS.with(|groups| groups.borrow().get(group).map_or(0, Vec::len))
+    }
+
+    #[test]
+    fn only_the_highest_priority_initialises() {
+        let low = InitGroupMember::new("initialises".into(), 0);
+        let high = InitGroupMember::new("initialises".into(), 1);
+        assert!(!low.should_apply(ChangeOrigin::Init));
+        assert!(high.should_apply(ChangeOrigin::Init));
+    }
+
+    #[test]
+    fn later_changes_always_apply() {
+        let low = InitGroupMember::new("later".into(), 0);
+        let _high = InitGroupMember::new("later".into(), 1);
+        assert!(low.should_apply(ChangeOrigin::UserWidget));
+        assert!(low.should_apply(ChangeOrigin::Programmatic));
+    }
+
+    #[test]
+    fn members_leave_when_dropped() {
+        let low = InitGroupMember::new("dropped".into(), 0);
+        let high = InitGroupMember::new("dropped".into(), 1);
+        assert_eq!(group_size("dropped"), 2);
+        drop(high);
+        assert_eq!(group_size("dropped"), 1);
+        assert!(low.should_apply(ChangeOrigin::Init));
+        drop(low);
+        assert!(GROUPS.with(|groups| !groups.borrow().contains_key("dropped")));
+    }
+
+    #[cfg(feature = "druid-master")]
+    mod hosts {
+        use super::*;
+        use crate::binding::{BindingHost, LensPropBinding};
+        use crate::testing::{EditMock, MockValueProperty, MockWidget, EDIT_MOCK};
+        use druid::lens;
+        use druid::tests::harness::Harness;
+        use druid::widget::prelude::*;
+        use druid::widget::{ControllerHost, Flex};
+        use std::rc::Rc;
+
+        fn host(id: WidgetId, value: i32, priority: i32, seen: &Rc<Cell<i32>>) -> impl Widget<i32> {
+            let edit = EditMock { seen: seen.clone() };
+            BindingHost::<i32, i32, _, _, _>::new(
+                ControllerHost::new(MockWidget::new(value), edit),
+                LensPropBinding::new(lens::Identity, MockValueProperty::default()),
+            )
+            .init_priority("hosts", priority)
+            .with_id(id)
+        }
+
+        #[test]
+        fn the_higher_priority_host_initialises_the_data() {
+            let (low_seen, high_seen) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+            let root = Flex::row()
+                .with_child(host(WidgetId::next(), 10, 0, &low_seen))
+                .with_child(host(WidgetId::next(), 20, 1, &high_seen));
+            Harness::create_simple(0, root, |harness| {
+                harness.send_initial_events();
+                harness.just_layout();
+                assert_eq!(*harness.data(), 20);
+                assert_eq!(low_seen.get(), 20);
+                assert_eq!(high_seen.get(), 20);
+            });
+        }
+
+        #[test]
+        fn later_edits_apply_whatever_the_priority() {
+            let (low_seen, high_seen) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+            let low = WidgetId::next();
+            let root = Flex::row()
+                .with_child(host(low, 10, 0, &low_seen))
+                .with_child(host(WidgetId::next(), 20, 1, &high_seen));
+            Harness::create_simple(0, root, |harness| {
+                harness.send_initial_events();
+                harness.just_layout();
+                harness.submit_command(EDIT_MOCK.with(5).to(low));
+                assert_eq!(*harness.data(), 5);
+                assert_eq!(low_seen.get(), 5);
+                assert_eq!(high_seen.get(), 5);
+            });
+        }
+
+        #[cfg(feature = "druid-widgets")]
+        mod scrolls {
+            use super::*;
+            use crate::binding::BindingHostHooks;
+            use crate::testing::PropertyHarness;
+            use crate::ScrollToProperty;
+            use druid::widget::{Axis, Scroll, SizedBox};
+
+            /// Counts the changes a host applies while it initialises
+            struct InitApplies(Rc<Cell<usize>>);
+
+            impl<T, C> BindingHostHooks<T, C> for InitApplies {
+                fn on_change(
+                    &mut self,
+                    _controlled: &mut C,
+                    _ctx: &mut EventCtx,
+                    _data: &T,
+                    origin: ChangeOrigin,
+                    _env: &Env,
+                ) {
+                    if origin == ChangeOrigin::Init {
+                        self.0.set(self.0.get() + 1)
+                    }
+                }
+            }
+
+            type Scrolled = Scroll<f64, SizedBox<f64>>;
+
+            fn scroll(priority: i32, applies: &Rc<Cell<usize>>) -> impl Widget<f64> {
+                BindingHost::<f64, f64, Scrolled, _, _>::new(
+                    scrolled(),
+                    LensPropBinding::new(lens::Identity, ScrollToProperty::new(Axis::Vertical)),
+                )
+                .init_priority("scrolls", priority)
+                .with_hooks(InitApplies(applies.clone()))
+            }
+
+            fn scrolled() -> Scrolled {
+                Scroll::new(SizedBox::empty().width(100.).height(1000.)).vertical()
+            }
+
+            #[test]
+            fn a_scroll_not_yet_laid_out_echoes_a_clamped_offset() {
+                PropertyHarness::run(
+                    ScrollToProperty::new(Axis::Vertical),
+                    scrolled(),
+                    30.,
+                    |harness| {
+                        assert_eq!(harness.controlled().offset_for_axis(Axis::Vertical), 0.);
+                        assert!(harness.read_back());
+                        assert_eq!(*harness.field(), 0.);
+                    },
+                );
+            }
+
+            #[test]
+            fn only_one_of_two_scrolls_initialises_their_shared_offset() {
+                // Neither Scroll has been laid out when the hosts initialise, so as above both clamp
+                // the offset they are given, and would both write that back over the data
+                let (low_applies, high_applies) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+                let root = Flex::row()
+                    .with_child(SizedBox::new(scroll(0, &low_applies)).height(100.))
+                    .with_child(SizedBox::new(scroll(1, &high_applies)).height(100.));
+                Harness::create_simple(30., root, |harness| {
+                    harness.send_initial_events();
+                    harness.just_layout();
+                    assert_eq!(low_applies.get(), 0);
+                    assert_eq!(high_applies.get(), 1);
+                    assert_eq!(*harness.data(), 0.);
+                });
+            }
+        }
+    }
+}
@@ -1,15 +1,205 @@
+#[cfg(not(any(feature = "druid-master", feature = "druid-0-7")))]
+compile_error!("druid_bindings needs one of the druid-master or druid-0-7 features");
+
+// Modules refer to whichever druid was chosen as `druid`
+#[cfg(all(feature = "druid-0-7", not(feature = "druid-master")))]
+extern crate druid_0_7 as druid;
+
+#[macro_use]
+mod bind_macro;
 #[macro_use]
 mod bindable_access;
+#[macro_use]
+mod props_macro;
 
+mod adopt;
+mod badged;
+mod baseline;
+mod bindable_controller;
+mod bindable_painter;
+mod bindable_pod;
+mod bindable_rich_label;
+mod bindable_scroll;
+mod bindable_text_box;
+mod bindable_transform;
 mod binding;
+#[cfg(feature = "druid-master")]
+mod bridge;
+mod change;
+mod click_counter;
+mod clipboard;
+mod collapsible;
+mod command_writer;
+mod compat;
+mod context_menu;
+mod cursor;
+mod device_pixels;
+mod diagnostics;
+#[cfg(feature = "druid-widgets")]
 mod druid_widgets;
+mod dynamic_binding;
+mod event_pulse;
+mod exclusive;
+mod fallible;
+#[cfg(feature = "druid-widgets")]
+mod forms;
+mod init_group;
+#[cfg(feature = "druid-master")]
+mod menu_lens;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "panic-guard")]
+mod panic_guard;
+mod property_adapters;
+mod ranged;
+mod relative_time;
+mod remember;
+mod simple_binding;
+mod simple_property;
+#[cfg(all(feature = "druid-widgets", feature = "druid-master"))]
+mod smooth_scroll;
+#[cfg(feature = "persist")]
+mod snapshot;
+mod tooltip;
+mod wheel_sensitivity;
+mod zoom_box;
+
+/// The traits and combinators needed to bind widgets, and the properties of druid's widgets,
+/// for a single glob import: `use druid_bindings::prelude::*;`
+/// Properties added for druid's widgets are picked up here as they are added.
+pub mod prelude;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use adopt::{field_is, field_is_default, AdoptIfUnsetBinding};
+
+pub use badged::{BadgeCountProperty, BadgeTextProperty, BadgeVisibleProperty, Badged};
+
+pub use baseline::{BaselineOffsetProperty, BaselineProbe};
 
 pub use bindable_access::{BindableAccess};
 
+pub use bindable_controller::{BindableController, ControllerState, ControllerStateProperty};
+
+pub use bindable_painter::{BindablePainter, PainterStyleProperty};
+
+pub use bindable_pod::BindablePod;
+
+pub use bindable_rich_label::{
+    AttributePatch, AttributePatches, BindableRichLabel, RichLabelMatchCountProperty,
+    RichLabelPatchesProperty, RichLabelSearchProperty, RichLabelTextProperty,
+};
+
+pub use bindable_scroll::{
+    BindableScroll, BindableScrollAlignmentProperty, BindableScrollLimitProperty,
+    BindableScrollOffsetProperty, BindableScrollSnapProperty, BindableScrollViewportProperty,
+    SnapPoints,
+};
+
+#[cfg(feature = "druid-master")]
+pub use bindable_text_box::TextBoxCompositionProperty;
+pub use bindable_text_box::{BindableTextBox, TextBoxTextDeltaProperty, TextBoxTextProperty};
+
+pub use bindable_transform::{
+    BindableTransform, TransformRotationProperty, TransformScaleProperty,
+    TransformTranslationProperty,
+};
+
 pub use binding::{
-    BindableProperty, Binding, BindingExt, BindingHost,
-    DataToWidgetOnlyBinding, LensBinding, LensBindingExt, LensPropBinding, WidgetBindingExt,
-    WidgetToDataOnlyBinding,
+    BindableProperty, Binding, BindingExt, BindingHost, BindingHostHooks, ChangeOrigin,
+    ConvertLens, ConvertedLens, DataToWidgetOnlyBinding, EnvEnabledBinding, FilteredBinding,
+    LensBinding, LensBindingExt, LensPropBinding, MirrorBinding, PrioritisedBinding, TraceField,
+    TracedField, TracingBinding, WidgetBindingExt, WidgetToDataOnlyBinding, BINDING_CHANGED,
+    SYNC_FROM_DATA, SYNC_FROM_WIDGET,
+};
+
+#[cfg(feature = "druid-master")]
+pub use bridge::{bridge, Bridged, BridgedData, BridgedShared};
+
+pub use change::{append_change, Conflate, Delta, TextDelta, TextEdit, Versioned};
+
+pub use click_counter::{ClickCountProperty, ClickCounter};
+
+pub use clipboard::ClipboardProperty;
+
+pub use collapsible::{Collapsible, CollapsibleExpandedProperty};
+
+pub use command_writer::CommandWriter;
+
+pub use context_menu::{ContextMenuArea, ContextMenuBinding, ContextMenuDesc};
+
+pub use cursor::{CursorArea, CursorProperty};
+
+pub use device_pixels::{DevicePixelsProperty, DeviceScalable};
+
+pub use diagnostics::{short_type_name, StuckAction};
+
+pub use dynamic_binding::{
+    AnyBinding, AttachBindings, BoxedBinding, DynamicBindings, NamedBinding, ADD_BINDING,
+    REMOVE_BINDING,
+};
+
+pub use event_pulse::{EventPulse, EventPulseCountProperty, EventPulseFlagProperty};
+
+pub use exclusive::ExclusiveGroupBinding;
+
+pub use fallible::{
+    BindingError, NoErrorLens, RejectionPolicy, TryBindableProperty, TryLensPropBinding,
+};
+
+#[cfg(feature = "druid-widgets")]
+pub use forms::{FormField, ValidationBinding};
+
+#[cfg(feature = "druid-master")]
+pub use menu_lens::{MenuItemLensExt, MenuLensExt};
+
+#[cfg(feature = "metrics")]
+pub use metrics::{SyncMetrics, DUMP_BINDING_METRICS};
+
+#[cfg(feature = "panic-guard")]
+pub use panic_guard::{PanicGuardBinding, PanicMode};
+
+pub use property_adapters::{
+    BindablePropertyExt, BoolToggleProperty, ClampedProperty, FormattedProperty, InvertedProperty,
+    OnNone, OptionalProperty, SnappedProperty, TriState, TriStateProperty,
 };
 
-pub use druid_widgets::ScrollToProperty;
\ No newline at end of file
+pub use ranged::{HasRange, RangeBoundsProperty, RangeValueProperty};
+
+pub use relative_time::{humanize_elapsed, RelativeTimeProperty};
+
+pub use remember::{remember_scroll, RememberLens, RememberedBinding, RememberedScroll};
+
+pub use simple_binding::{Simple, SimpleBinding};
+
+pub use simple_property::{ClosureProperty, Requests, SimpleValueProperty};
+
+#[cfg(feature = "persist")]
+pub use snapshot::{BindingSnapshot, SnapshotBinding};
+
+#[cfg(feature = "druid-widgets")]
+pub use druid_widgets::{
+    DirectedAlignment, DirectedAlignmentProperty, LabelFontProperty, LabelLineBreakingProperty,
+    LabelStyle, LabelStyleProperty, LabelTextAlignmentProperty, LabelTextColorProperty,
+    LabelTextProperty, LabelTextSizeProperty, RawLabelTextAlignmentProperty, TextAlignable,
+    TextDirection,
+};
+
+#[cfg(all(feature = "druid-widgets", feature = "druid-master"))]
+pub use druid_widgets::{
+    scroll_offsets, AxisEdges, AxisFractionProperty, AxisFractionsProperty, ClipBoxPanByProperty,
+    ClipBoxViewportOriginProperty, ScrollContentSizeProperty, ScrollEdgeProperty,
+    ScrollLimitProperty, ScrollOffsetsBinding, ScrollToProperty, ScrollViewportOriginProperty,
+    ScrollbarAxisEnabledProperty, ScrollbarsEnabledProperty, TabStep, TabsIndexProperty,
+    TabsTriggerProperty,
+};
+
+#[cfg(all(feature = "druid-widgets", feature = "druid-master"))]
+pub use smooth_scroll::{Easing, SmoothScroll, SmoothScrollToProperty};
+
+pub use tooltip::{BindableTooltip, TooltipDelayProperty, TooltipTextProperty};
+
+pub use wheel_sensitivity::{WheelSensitivity, WheelSensitivityProperty};
+
+pub use zoom_box::{ZoomAnchorProperty, ZoomBox, ZoomScaleProperty};
@@ -0,0 +1,44 @@
+use druid::{Data, Lens, LensExt, Menu, MenuItem};
+
+/// Lens based state for druid's menus and menu items, so they follow a field of the data
+/// rather than being built again by hand, eg in an AppDelegate.
+/// Druid checks a menu's state against the data whenever the data changes.
+pub trait MenuLensExt<T>: Sized {
+    /// Enable this only while the bool field lens points at is true
+    fn enabled_by(self, lens: impl Lens<T, bool> + 'static) -> Self;
+}
+
+impl<T: Data> MenuLensExt<T> for Menu<T> {
+    fn enabled_by(self, lens: impl Lens<T, bool> + 'static) -> Self {
+        self.enabled_if(move |data, _env| lens.get(data))
+    }
+}
+
+impl<T: Data> MenuLensExt<T> for MenuItem<T> {
+    fn enabled_by(self, lens: impl Lens<T, bool> + 'static) -> Self {
+        self.enabled_if(move |data, _env| lens.get(data))
+    }
+}
+
+/// Lens based checked state for druid's menu items
+pub trait MenuItemLensExt<T>: Sized {
+    /// Show this item checked while the bool field lens points at is true
+    fn selected_by(self, lens: impl Lens<T, bool> + 'static) -> Self;
+
+    /// Show this item checked while the bool field lens points at is true,
+    /// and flip the field when the item is chosen, so that the menu changes the data as well as showing it.
+    /// This replaces any on_activate callback the item already has.
+    fn toggles(self, lens: impl Lens<T, bool> + Clone + 'static) -> Self;
+}
+
+impl<T: Data> MenuItemLensExt<T> for MenuItem<T> {
+    fn selected_by(self, lens: impl Lens<T, bool> + 'static) -> Self {
+        self.selected_if(move |data, _env| lens.get(data))
+    }
+
+    fn toggles(self, lens: impl Lens<T, bool> + Clone + 'static) -> Self {
+        let toggled = lens.clone();
+        self.selected_by(lens)
+            .on_activate(move |_ctx, data, _env| toggled.with_mut(data, |on| *on = !*on))
+    }
+}
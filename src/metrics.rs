@@ -0,0 +1,51 @@
+use druid::Selector;
+
+/// Counts of the synchronisations a BindingHost has performed, to help find chatty bindings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncMetrics {
+    /// Times the data (or the Env) was written to the widget
+    pub data_to_widget: u64,
+    /// Times changes from the widget were applied to the data
+    pub widget_to_data: u64,
+    /// Updates where neither the data nor any Env the binding depends on had changed, so nothing was written
+    pub skipped: u64,
+}
+
+/// Sending this command to binding hosts makes each of them log its SyncMetrics at info level.
+/// Hosts don't handle it, so one command sent to a window reaches every host in it.
+pub const DUMP_BINDING_METRICS: Selector = Selector::new("druid-bindings.dump-binding-metrics");
+
+/// A host's metrics along with a description of its bindings.
+/// They are logged as they are dropped, so a run of the app can be examined after closing it.
+/// The host itself has no Drop, so that it can be taken apart by BindingHost::binding.
+pub(crate) struct HostMetrics {
+    pub(crate) counts: SyncMetrics,
+    description: String,
+}
+
+impl HostMetrics {
+    /// No syncs yet, for the bindings described
+    pub(crate) fn new(description: String) -> Self {
+        HostMetrics {
+            counts: Default::default(),
+            description,
+        }
+    }
+
+    /// The same counts, for different bindings
+    pub(crate) fn describing(mut self, description: String) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Log the counts at info level
+    pub(crate) fn log(&self) {
+        log::info!("BindingHost ({}): {:?}", self.description, self.counts);
+    }
+}
+
+impl Drop for HostMetrics {
+    fn drop(&mut self) {
+        self.log()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::binding::Binding;
+use druid::{Env, EventCtx, UpdateCtx};
+use std::any::type_name;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// What a PanicGuardBinding does once it has logged a panic in the binding it guards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicMode {
+    /// Carry on panicking, as if the guard wasn't there
+    Resume,
+    /// Drop the write or change that panicked and carry on. Whatever the panicking lens or property
+    /// had already done, eg half of a data update, stays done.
+    Drop,
+}
+
+/// This wraps another binding, catching panics from it, eg from a lens that can't reach its field,
+/// to log which binding panicked before resuming or dropping the write.
+/// The log names the binding and its type, which identifies the lens and property.
+pub struct PanicGuardBinding<B> {
+    name: String,
+    mode: PanicMode,
+    binding: B,
+}
+
+impl<B> PanicGuardBinding<B> {
+    /// Guard binding, identifying it in the log by name
+    pub fn new(name: impl Into<String>, mode: PanicMode, binding: B) -> Self {
+        PanicGuardBinding {
+            name: name.into(),
+            mode,
+            binding,
+        }
+    }
+
+    fn guard(&self, direction: &str, f: impl FnOnce()) {
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(f)) {
+            log::error!(
+                "binding '{}' ({}) panicked applying {}",
+                self.name,
+                type_name::<B>(),
+                direction
+            );
+            if self.mode == PanicMode::Resume {
+                resume_unwind(panic)
+            }
+        }
+    }
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled> for PanicGuardBinding<B> {
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.guard("data -> widget", || {
+            self.binding
+                .apply_data_to_controlled(data, controlled, ctx, env)
+        })
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.guard("widget change", || {
+            self.binding
+                .append_change_required(controlled, data, change, env)
+        })
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        self.guard("widget -> data", || {
+            self.binding
+                .apply_change_to_data(controlled, data, change, ctx, env)
+        })
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.guard("env -> widget", || {
+            self.binding
+                .apply_env_to_controlled(data, controlled, ctx, env)
+        })
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        self.binding.wants_initial_data()
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.guard("initial data -> widget", || {
+            self.binding
+                .apply_initial_data_to_controlled(data, controlled, ctx, env)
+        })
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}.guard_panics({:?}, {:?})",
+            self.binding.describe(),
+            self.name,
+            self.mode
+        )
+    }
+}
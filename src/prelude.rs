@@ -0,0 +1,10 @@
+pub use crate::bind;
+pub use crate::binding::{BindableProperty, Binding, BindingExt, LensBindingExt, WidgetBindingExt};
+#[cfg(feature = "druid-widgets")]
+pub use crate::druid_widgets::*;
+pub use crate::fallible::{RejectionPolicy, TryBindableProperty};
+#[cfg(feature = "druid-master")]
+pub use crate::menu_lens::{MenuItemLensExt, MenuLensExt};
+pub use crate::property_adapters::{BindablePropertyExt, OnNone};
+pub use crate::simple_binding::{Simple, SimpleBinding};
+pub use crate::BindableAccess;
@@ -0,0 +1,706 @@
+use crate::binding::BindableProperty;
+use crate::compat::Formatter;
+use crate::device_pixels::{DevicePixelsProperty, DeviceScalable};
+use crate::relative_time::RelativeTimeProperty;
+use druid::{Data, Env, EventCtx, UpdateCtx};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+/// What an OptionalProperty writes to its controlled item when the bound field is None.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnNone<V> {
+    /// Leave the controlled item as it is. Changes from the widget are not written back while the field is None.
+    Skip,
+    /// Write the default value of the property type
+    Default,
+    /// Write the given value, eg to reset the widget to some initial state
+    Value(V),
+}
+
+/// Adapts a property so it can be bound to an Option of its value.
+/// Changes from the widget always produce Some.
+pub struct OptionalProperty<P: BindableProperty> {
+    prop: P,
+    on_none: OnNone<P::Value>,
+}
+
+impl<P: BindableProperty> OptionalProperty<P>
+where
+    P::Value: Clone + Default,
+{
+    /// Adapt a property to Option values, writing according to on_none when the field is None
+    pub fn new(prop: P, on_none: OnNone<P::Value>) -> Self {
+        OptionalProperty { prop, on_none }
+    }
+
+    /// The value standing in for None, if there is one
+    fn none_value(&self) -> Option<P::Value> {
+        match &self.on_none {
+            OnNone::Skip => None,
+            OnNone::Default => Some(Default::default()),
+            OnNone::Value(value) => Some(value.clone()),
+        }
+    }
+}
+
+impl<P: BindableProperty> BindableProperty for OptionalProperty<P>
+where
+    P::Value: Clone + Default,
+{
+    type Controlled = P::Controlled;
+    type Value = Option<P::Value>;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        match field_val {
+            Some(value) => self.prop.write_prop(controlled, ctx, value, env),
+            None => {
+                if let Some(value) = self.none_value() {
+                    self.prop.write_prop(controlled, ctx, &value, env)
+                }
+            }
+        }
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        match field_val {
+            Some(value) => self.prop.append_changes(controlled, value, change, env),
+            None => {
+                if let Some(value) = self.none_value() {
+                    self.prop.append_changes(controlled, &value, change, env)
+                }
+            }
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        let value = field.get_or_insert_with(|| self.none_value().unwrap_or_default());
+        self.prop
+            .update_data_from_change(controlled, ctx, value, change, env)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.optional(..)", self.prop.name())
+    }
+}
+
+/// Adapts a String property so it can be bound to a value shown as text, eg a number in a Label or TextBox,
+/// formatting with a druid Formatter one way and parsing with it the other. Locale conventions, such as
+/// currency symbols and digit grouping, are up to the formatter.
+/// Text that doesn't parse leaves the field as it is.
+pub struct FormattedProperty<P, V, F> {
+    prop: P,
+    formatter: F,
+    phantom_v: PhantomData<V>,
+}
+
+impl<P: BindableProperty<Value = String>, V, F: Formatter<V>> FormattedProperty<P, V, F> {
+    /// Adapt a String property to values formatted and parsed by formatter
+    pub fn new(prop: P, formatter: F) -> Self {
+        FormattedProperty {
+            prop,
+            formatter,
+            phantom_v: Default::default(),
+        }
+    }
+}
+
+impl<P: BindableProperty<Value = String>, V, F: Formatter<V>> BindableProperty
+    for FormattedProperty<P, V, F>
+{
+    type Controlled = P::Controlled;
+    type Value = V;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        self.prop
+            .write_prop(controlled, ctx, &self.formatter.format(field_val), env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.prop
+            .append_changes(controlled, &self.formatter.format(field_val), change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        let mut text = self.formatter.format(field);
+        self.prop
+            .update_data_from_change(controlled, ctx, &mut text, change, env);
+        if let Ok(value) = self.formatter.value(&text) {
+            *field = value
+        }
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.formatted(..)", self.prop.name())
+    }
+}
+
+/// Adapts a property so values outside of a range are clamped into it, in both directions.
+/// Data is clamped before it is written to the controlled item, and values read back from the
+/// controlled item are clamped before they reach the data.
+pub struct ClampedProperty<P: BindableProperty> {
+    prop: P,
+    range: RangeInclusive<P::Value>,
+}
+
+impl<P: BindableProperty> ClampedProperty<P>
+where
+    P::Value: PartialOrd + Clone,
+{
+    /// Clamp the values of prop into range
+    pub fn new(prop: P, range: RangeInclusive<P::Value>) -> Self {
+        ClampedProperty { prop, range }
+    }
+
+    fn clamp(&self, value: &P::Value) -> P::Value {
+        if value < self.range.start() {
+            self.range.start().clone()
+        } else if value > self.range.end() {
+            self.range.end().clone()
+        } else {
+            value.clone()
+        }
+    }
+}
+
+impl<P: BindableProperty> BindableProperty for ClampedProperty<P>
+where
+    P::Value: PartialOrd + Clone,
+{
+    type Controlled = P::Controlled;
+    type Value = P::Value;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        self.prop
+            .write_prop(controlled, ctx, &self.clamp(field_val), env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.prop
+            .append_changes(controlled, &self.clamp(field_val), change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        self.prop
+            .update_data_from_change(controlled, ctx, field, change, env);
+        *field = self.clamp(field)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.clamped(..)", self.prop.name())
+    }
+}
+
+/// Adapts a numeric property so values are rounded to the nearest multiple of a step, in both directions.
+/// Use it to keep float noise from round trips through the controlled item out of the data.
+pub struct SnappedProperty<P> {
+    prop: P,
+    step: f64,
+}
+
+impl<P: BindableProperty<Value = f64>> SnappedProperty<P> {
+    /// Snap the values of prop to multiples of step. The step must be positive.
+    pub fn new(prop: P, step: f64) -> Self {
+        debug_assert!(step > 0., "snapping step must be positive");
+        SnappedProperty { prop, step }
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        (value / self.step).round() * self.step
+    }
+}
+
+impl<P: BindableProperty<Value = f64>> BindableProperty for SnappedProperty<P> {
+    type Controlled = P::Controlled;
+    type Value = f64;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        self.prop
+            .write_prop(controlled, ctx, &self.snap(*field_val), env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.prop
+            .append_changes(controlled, &self.snap(*field_val), change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        self.prop
+            .update_data_from_change(controlled, ctx, field, change, env);
+        *field = self.snap(*field)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.snapped({})", self.prop.name(), self.step)
+    }
+}
+
+/// A checkbox style state derived from a collection: all, some or none of its members are selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum TriState {
+    /// No members are selected, or there are none
+    Unchecked,
+    /// Some but not all members are selected
+    Indeterminate,
+    /// All members are selected
+    Checked,
+}
+
+impl TriState {
+    /// The state of a collection with these selection flags
+    pub fn of(selected: impl IntoIterator<Item = bool>) -> Self {
+        let (mut any, mut all) = (false, true);
+        for selected in selected {
+            any |= selected;
+            all &= selected;
+        }
+        match (any, all) {
+            (true, true) => TriState::Checked,
+            (true, false) => TriState::Indeterminate,
+            _ => TriState::Unchecked,
+        }
+    }
+
+    /// The selection this state sets on every member, if it is not indeterminate
+    pub fn as_bool(self) -> Option<bool> {
+        match self {
+            TriState::Unchecked => Some(false),
+            TriState::Indeterminate => None,
+            TriState::Checked => Some(true),
+        }
+    }
+}
+
+impl From<bool> for TriState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            TriState::Checked
+        } else {
+            TriState::Unchecked
+        }
+    }
+}
+
+/// Adapts a bool property so it can be bound to a TriState, eg for a select-all checkbox
+/// bound through a lens deriving the state from a collection:
+///
+/// ```ignore
+/// let all = lens::Map::new(
+///     |items: &Vector<Item>| TriState::of(items.iter().map(|i| i.selected)),
+///     |items: &mut Vector<Item>, state: TriState| {
+///         if let Some(selected) = state.as_bool() {
+///             items.iter_mut().for_each(|i| i.selected = selected)
+///         }
+///     },
+/// );
+/// ```
+///
+/// Indeterminate is written to the bool property as false. Widgets that can show an indeterminate
+/// state get it through with_indeterminate. Changes from the widget are always Checked or Unchecked.
+pub struct TriStateProperty<P: BindableProperty<Value = bool>> {
+    prop: P,
+    set_indeterminate: Option<fn(&mut P::Controlled, bool)>,
+}
+
+impl<P: BindableProperty<Value = bool>> TriStateProperty<P> {
+    /// Adapt a bool property to TriState values
+    pub fn new(prop: P) -> Self {
+        TriStateProperty {
+            prop,
+            set_indeterminate: None,
+        }
+    }
+
+    /// Also tell the controlled item whether the state is indeterminate, for widgets that can show it
+    pub fn with_indeterminate(mut self, set_indeterminate: fn(&mut P::Controlled, bool)) -> Self {
+        self.set_indeterminate = Some(set_indeterminate);
+        self
+    }
+}
+
+impl<P: BindableProperty<Value = bool>> BindableProperty for TriStateProperty<P> {
+    type Controlled = P::Controlled;
+    type Value = TriState;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        if let Some(set_indeterminate) = self.set_indeterminate {
+            set_indeterminate(controlled, *field_val == TriState::Indeterminate)
+        }
+        self.prop
+            .write_prop(controlled, ctx, &(*field_val == TriState::Checked), env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.prop
+            .append_changes(controlled, &(*field_val == TriState::Checked), change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        let mut checked = *field == TriState::Checked;
+        self.prop
+            .update_data_from_change(controlled, ctx, &mut checked, change, env);
+        *field = checked.into()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.tri_state()", self.prop.name())
+    }
+}
+
+/// A bool property for checkbox-like toggles, made from a widget's getter and setter for its state.
+/// Writes request a paint, as toggling usually only changes how the widget looks.
+pub struct BoolToggleProperty<W> {
+    get: fn(&W) -> bool,
+    set: fn(&mut W, bool),
+}
+
+impl<W> BoolToggleProperty<W> {
+    /// Create a property reading the toggle's state with get, and setting it with set
+    pub fn new(get: fn(&W) -> bool, set: fn(&mut W, bool)) -> Self {
+        BoolToggleProperty { get, set }
+    }
+}
+
+impl<W> BindableProperty for BoolToggleProperty<W> {
+    type Controlled = W;
+    type Value = bool;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        (self.set)(controlled, *field_val);
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if (self.get)(controlled) != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = (self.get)(controlled)
+    }
+}
+
+/// Adapts a bool property so it is bound to the negation of the field, in both directions,
+/// eg to bind a collapsed flag to an expanded property.
+pub struct InvertedProperty<P> {
+    prop: P,
+}
+
+impl<P: BindableProperty<Value = bool>> InvertedProperty<P> {
+    /// Invert the values of prop
+    pub fn new(prop: P) -> Self {
+        InvertedProperty { prop }
+    }
+}
+
+impl<P: BindableProperty<Value = bool>> BindableProperty for InvertedProperty<P> {
+    type Controlled = P::Controlled;
+    type Value = bool;
+    type Change = P::Change;
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        self.prop.write_prop(controlled, ctx, &!*field_val, env)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.prop
+            .append_changes(controlled, &!*field_val, change, env)
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        ctx: &EventCtx,
+        field: &mut Self::Value,
+        change: Self::Change,
+        env: &Env,
+    ) {
+        let mut inverted = !*field;
+        self.prop
+            .update_data_from_change(controlled, ctx, &mut inverted, change, env);
+        *field = !inverted
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.inverted()", self.prop.name())
+    }
+}
+
+/// This trait provides combinators adapting bindable properties
+pub trait BindablePropertyExt: BindableProperty + Sized {
+    /// Bind this property to an Option of its value, writing according to on_none when the field is None
+    fn optional(self, on_none: OnNone<Self::Value>) -> OptionalProperty<Self>
+    where
+        Self::Value: Clone + Default,
+    {
+        OptionalProperty::new(self, on_none)
+    }
+
+    /// Clamp values into range in both directions
+    fn clamped(self, range: RangeInclusive<Self::Value>) -> ClampedProperty<Self>
+    where
+        Self::Value: PartialOrd + Clone,
+    {
+        ClampedProperty::new(self, range)
+    }
+
+    /// Bind this geometry property in device pixels rather than logical pixels
+    fn device_pixels(self) -> DevicePixelsProperty<Self>
+    where
+        Self::Value: DeviceScalable,
+    {
+        DevicePixelsProperty::new(self)
+    }
+
+    /// Round values to the nearest multiple of step in both directions
+    fn snapped(self, step: f64) -> SnappedProperty<Self>
+    where
+        Self: BindableProperty<Value = f64>,
+    {
+        SnappedProperty::new(self, step)
+    }
+
+    /// Bind this bool property to the negation of the field
+    fn inverted(self) -> InvertedProperty<Self>
+    where
+        Self: BindableProperty<Value = bool>,
+    {
+        InvertedProperty::new(self)
+    }
+
+    /// Bind this String property to a value, formatted and parsed by formatter
+    fn formatted<V, F: Formatter<V>>(self, formatter: F) -> FormattedProperty<Self, V, F>
+    where
+        Self: BindableProperty<Value = String>,
+    {
+        FormattedProperty::new(self, formatter)
+    }
+
+    /// Bind this String property to a timestamp, writing how long ago it was
+    fn relative_time(self) -> RelativeTimeProperty<Self>
+    where
+        Self: BindableProperty<Value = String>,
+    {
+        RelativeTimeProperty::new(self)
+    }
+
+    /// Bind this bool property to a TriState
+    fn tri_state(self) -> TriStateProperty<Self>
+    where
+        Self: BindableProperty<Value = bool>,
+    {
+        TriStateProperty::new(self)
+    }
+}
+
+impl<P: BindableProperty> BindablePropertyExt for P {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "druid-master")]
+    use crate::testing::{MockValueProperty, MockWidget, PropertyHarness};
+
+    #[cfg(feature = "druid-master")]
+    #[test]
+    fn clamped_properties_clamp_both_ways() {
+        let prop = MockValueProperty::default().clamped(0..=10);
+        PropertyHarness::run(prop, MockWidget::new(0), 20, |harness| {
+            assert_eq!(harness.controlled().value, 10);
+            harness.controlled_mut().value = -5;
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 0);
+        });
+    }
+
+    #[cfg(feature = "druid-master")]
+    #[test]
+    fn snapped_properties_snap_both_ways() {
+        let prop = MockValueProperty::default().snapped(0.5);
+        PropertyHarness::run(prop, MockWidget::new(0.), 1.26, |harness| {
+            assert_eq!(harness.controlled().value, 1.5);
+            harness.controlled_mut().value = 0.74;
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 0.5);
+        });
+    }
+
+    #[test]
+    fn tri_states_follow_the_selection() {
+        assert_eq!(TriState::of(vec![]), TriState::Unchecked);
+        assert_eq!(TriState::of(vec![false, false]), TriState::Unchecked);
+        assert_eq!(TriState::of(vec![true, false]), TriState::Indeterminate);
+        assert_eq!(TriState::of(vec![true, true]), TriState::Checked);
+        assert_eq!(TriState::Indeterminate.as_bool(), None);
+        assert_eq!(TriState::from(true).as_bool(), Some(true));
+    }
+
+    #[cfg(feature = "druid-master")]
+    #[test]
+    fn tri_state_properties_write_indeterminate_as_unchecked() {
+        let prop = MockValueProperty::default().tri_state();
+        PropertyHarness::run(prop, MockWidget::new(true), TriState::Checked, |harness| {
+            harness.write(TriState::Indeterminate);
+            assert!(!harness.controlled().value);
+            harness.controlled_mut().value = true;
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), TriState::Checked);
+        });
+    }
+}
@@ -0,0 +1,75 @@
+/// Declare write-only bindable properties for a widget's setter methods.
+///
+/// Each property becomes a struct implementing BindableProperty, which writes the bound value
+/// through the named setter, then makes the named request on the context:
+///
+/// ```ignore
+/// bindable_props! {
+///     impl<T: Data> for Label<T> {
+///         /// The colour of the label's text
+///         pub LabelTextColorProperty: KeyOrValue<Color> => set_text_color, request_layout;
+///         pub LabelTextSizeProperty: KeyOrValue<f64> => set_text_size, request_layout;
+///     }
+/// }
+/// ```
+///
+/// The setter is passed a clone of the value, so setters taking `impl Into<_>` work as they are.
+/// The properties are constructed with Default.
+#[macro_export]
+macro_rules! bindable_props {
+    (impl<$($gen:ident $(: $bound:path)?),*> for $controlled:ty { $($props:tt)* }) => {
+        $crate::bindable_props!(@props [$($gen $(: $bound)?),*] [$controlled] $($props)*);
+    };
+    (@props [$($gen:ident $(: $bound:path)?),*] [$controlled:ty]) => {};
+    (@props [$($gen:ident $(: $bound:path)?),*] [$controlled:ty]
+        $(#[$meta:meta])* $vis:vis $name:ident: $value:ty => $setter:ident, $request:ident;
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis struct $name<$($gen),*>(::std::marker::PhantomData<($($gen,)*)>);
+
+        impl<$($gen),*> Default for $name<$($gen),*> {
+            fn default() -> Self {
+                $name(::std::marker::PhantomData)
+            }
+        }
+
+        impl<$($gen $(: $bound)?),*> $crate::BindableProperty for $name<$($gen),*> {
+            type Controlled = $controlled;
+            type Value = $value;
+            type Change = ();
+
+            fn write_prop(
+                &self,
+                controlled: &mut Self::Controlled,
+                ctx: &mut ::druid::UpdateCtx,
+                field_val: &Self::Value,
+                _env: &::druid::Env,
+            ) {
+                controlled.$setter(field_val.clone());
+                ctx.$request()
+            }
+
+            fn append_changes(
+                &self,
+                _controlled: &Self::Controlled,
+                _field_val: &Self::Value,
+                _change: &mut Option<Self::Change>,
+                _env: &::druid::Env,
+            ) {
+            }
+
+            fn update_data_from_change(
+                &self,
+                _controlled: &Self::Controlled,
+                _ctx: &::druid::EventCtx,
+                _field: &mut Self::Value,
+                _change: Self::Change,
+                _env: &::druid::Env,
+            ) {
+            }
+        }
+
+        $crate::bindable_props!(@props [$($gen $(: $bound)?),*] [$controlled] $($rest)*);
+    };
+}
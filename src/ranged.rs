@@ -0,0 +1,122 @@
+use crate::binding::BindableProperty;
+use druid::{Data, Env, EventCtx, UpdateCtx};
+use std::marker::PhantomData;
+
+/// Implemented by ranged widgets, eg sliders, steppers and knobs, to make RangeValueProperty and
+/// RangeBoundsProperty bindable on them.
+pub trait HasRange {
+    /// The current value
+    fn value(&self) -> f64;
+    /// Set the value, which the widget may limit to its range
+    fn set_value(&mut self, value: f64);
+    /// The lowest value
+    fn min(&self) -> f64;
+    /// The highest value
+    fn max(&self) -> f64;
+    /// Set the lowest and highest values
+    fn set_range(&mut self, min: f64, max: f64);
+}
+
+/// A bindable property for the value of a HasRange widget
+pub struct RangeValueProperty<W> {
+    phantom_w: PhantomData<W>,
+}
+
+impl<W> Default for RangeValueProperty<W> {
+    fn default() -> Self {
+        RangeValueProperty {
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<W: HasRange> BindableProperty for RangeValueProperty<W> {
+    type Controlled = W;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_value(*field_val);
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.value().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.value()
+    }
+}
+
+/// A write-only property for the (min, max) range of a HasRange widget.
+/// When binding it alongside RangeValueProperty, give it the higher priority,
+/// so the range is in place before a value within it is written.
+pub struct RangeBoundsProperty<W> {
+    phantom_w: PhantomData<W>,
+}
+
+impl<W> Default for RangeBoundsProperty<W> {
+    fn default() -> Self {
+        RangeBoundsProperty {
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<W: HasRange> BindableProperty for RangeBoundsProperty<W> {
+    type Controlled = W;
+    type Value = (f64, f64);
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_range(field_val.0, field_val.1);
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+}
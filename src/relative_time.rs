@@ -0,0 +1,130 @@
+use crate::binding::BindableProperty;
+use druid::{Env, EventCtx, UpdateCtx};
+use std::time::{Duration, SystemTime};
+
+/// Describes how long ago something happened, eg "3 min ago"
+pub fn humanize_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", secs / 60),
+        3600..=7199 => "1 hour ago".to_string(),
+        7200..=86399 => format!("{} hours ago", secs / 3600),
+        86400..=172_799 => "yesterday".to_string(),
+        _ => format!("{} days ago", secs / 86400),
+    }
+}
+
+/// Adapts a String property, eg LabelTextProperty, to be bound to a timestamp,
+/// writing how long ago it was. Times in the future count as now.
+///
+/// The text only changes when it is written, so have the host rewrite it as time passes
+/// with BindingHost::refresh_every. This is write-only.
+pub struct RelativeTimeProperty<P> {
+    prop: P,
+    describe: fn(Duration) -> String,
+}
+
+impl<P: BindableProperty<Value = String>> RelativeTimeProperty<P> {
+    /// Write timestamps to prop with humanize_elapsed
+    pub fn new(prop: P) -> Self {
+        RelativeTimeProperty {
+            prop,
+            describe: humanize_elapsed,
+        }
+    }
+
+    /// Describe the time elapsed with describe instead, eg to localise it
+    pub fn with_description(mut self, describe: fn(Duration) -> String) -> Self {
+        self.describe = describe;
+        self
+    }
+}
+
+impl<P: BindableProperty<Value = String>> BindableProperty for RelativeTimeProperty<P> {
+    type Controlled = P::Controlled;
+    type Value = SystemTime;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        env: &Env,
+    ) {
+        let elapsed = SystemTime::now()
+            .duration_since(*field_val)
+            .unwrap_or_default();
+        self.prop
+            .write_prop(controlled, ctx, &(self.describe)(elapsed), env)
+    }
+
+    fn append_changes(
+        &self,
+        _controlled: &Self::Controlled,
+        _field_val: &Self::Value,
+        _change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+    }
+
+    fn update_data_from_change(
+        &self,
+        _controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        _field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn name(&self) -> String {
+        format!("{}.relative_time()", self.prop.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_times_are_humanised_at_each_threshold() {
+        let humanized = |secs| humanize_elapsed(Duration::from_secs(secs));
+        assert_eq!(humanized(0), "just now");
+        assert_eq!(humanized(59), "just now");
+        assert_eq!(humanized(60), "1 min ago");
+        assert_eq!(humanized(3599), "59 min ago");
+        assert_eq!(humanized(3600), "1 hour ago");
+        assert_eq!(humanized(7199), "1 hour ago");
+        assert_eq!(humanized(7200), "2 hours ago");
+        assert_eq!(humanized(86399), "23 hours ago");
+        assert_eq!(humanized(86400), "yesterday");
+        assert_eq!(humanized(172_799), "yesterday");
+        assert_eq!(humanized(172_800), "2 days ago");
+        assert_eq!(humanized(10 * 86400), "10 days ago");
+    }
+
+    #[test]
+    fn parts_of_a_second_are_ignored() {
+        assert_eq!(humanize_elapsed(Duration::from_millis(59_999)), "just now");
+    }
+
+    #[cfg(feature = "druid-master")]
+    #[test]
+    fn future_times_are_written_as_now() {
+        use crate::testing::{MockValueProperty, MockWidget, PropertyHarness};
+        let prop = RelativeTimeProperty::new(MockValueProperty::default());
+        let in_an_hour = SystemTime::now() + Duration::from_secs(3600);
+        let widget = MockWidget::new(String::new());
+        PropertyHarness::run(prop, widget, in_an_hour, |harness| {
+            assert_eq!(harness.controlled().value, "just now");
+            harness.write(SystemTime::now() - Duration::from_secs(120));
+            assert_eq!(harness.controlled().value, "2 min ago");
+        });
+    }
+}
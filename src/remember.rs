@@ -0,0 +1,172 @@
+use crate::binding::{BindableProperty, Binding, LensPropBinding, SYNC_FROM_DATA};
+use druid::lens::{LensExt, Then};
+use druid::{Env, EventCtx, Lens, UpdateCtx};
+use std::cell::Cell;
+
+/// How many times a RememberedBinding writes the remembered value again when the controlled item didn't take it,
+/// eg because a scroll's content wasn't laid out yet, before letting the controlled item's value win.
+const RESTORE_ATTEMPTS: u8 = 3;
+
+/// A lens to the remembered value under a key in a map, 0 until something is stored there
+pub struct RememberLens {
+    key: String,
+}
+
+impl RememberLens {
+    /// The value remembered under key
+    pub fn new(key: impl Into<String>) -> Self {
+        RememberLens { key: key.into() }
+    }
+}
+
+impl Lens<im::HashMap<String, f64>, f64> for RememberLens {
+    fn with<V, F: FnOnce(&f64) -> V>(&self, data: &im::HashMap<String, f64>, f: F) -> V {
+        f(&data.get(&self.key).copied().unwrap_or(0.))
+    }
+
+    fn with_mut<V, F: FnOnce(&mut f64) -> V>(
+        &self,
+        data: &mut im::HashMap<String, f64>,
+        f: F,
+    ) -> V {
+        let mut value = data.get(&self.key).copied().unwrap_or(0.);
+        let result = f(&mut value);
+        if data.get(&self.key) != Some(&value) {
+            data.insert(self.key.clone(), value);
+        }
+        result
+    }
+}
+
+/// This wraps another binding, restoring the data to the controlled item when its host is added,
+/// and not letting the controlled item overwrite the data until it has taken the restored value.
+/// Controlled items often can't take the value straight away, eg a scroll offset before the
+/// content has been laid out, so the host is asked to write it again a few times.
+pub struct RememberedBinding<B> {
+    binding: B,
+    restored: Cell<bool>,
+    attempts: Cell<u8>,
+}
+
+impl<B> RememberedBinding<B> {
+    /// Restore binding's data when its host is added
+    pub fn new(binding: B) -> Self {
+        RememberedBinding {
+            binding,
+            restored: Cell::new(false),
+            attempts: Cell::new(0),
+        }
+    }
+}
+
+impl<T, Controlled, B: Binding<T, Controlled>> Binding<T, Controlled> for RememberedBinding<B> {
+    type Change = B::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &Controlled,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        self.binding
+            .append_change_required(controlled, data, change, env);
+        if change.is_none() {
+            // The controlled item agrees with the data
+            self.restored.set(true)
+        }
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &Controlled,
+        data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        if !self.restored.get() && self.attempts.get() < RESTORE_ATTEMPTS {
+            self.attempts.set(self.attempts.get() + 1);
+            ctx.submit_command(SYNC_FROM_DATA.to(ctx.widget_id()));
+        } else {
+            self.restored.set(true);
+            self.binding
+                .apply_change_to_data(controlled, data, change, ctx, env)
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        self.binding.priority()
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.binding.env_dependent()
+    }
+
+    fn apply_env_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_env_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        true
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        self.binding
+            .apply_data_to_controlled(data, controlled, ctx, env)
+    }
+
+    fn describe(&self) -> String {
+        format!("RememberedBinding({})", self.binding.describe())
+    }
+}
+
+/// The binding remember_scroll makes, of the offset property P to the value under a key in the map at L
+pub type RememberedScroll<T, L, P> = RememberedBinding<
+    LensPropBinding<
+        T,
+        <P as BindableProperty>::Controlled,
+        f64,
+        Then<L, RememberLens, im::HashMap<String, f64>>,
+        P,
+    >,
+>;
+
+/// Remember a scroll offset under key in a map in the data, and restore it when the scroll is shown again,
+/// eg when a list in a tab is revisited. prop is a scroll offset property, such as BindableScrollOffsetProperty,
+/// or ScrollToProperty on druid master. Bind one per axis to remember both.
+pub fn remember_scroll<T, L, P>(
+    map: L,
+    key: impl Into<String>,
+    prop: P,
+) -> RememberedScroll<T, L, P>
+where
+    L: Lens<T, im::HashMap<String, f64>>,
+    P: BindableProperty<Value = f64>,
+{
+    RememberedBinding::new(LensPropBinding::new(map.then(RememberLens::new(key)), prop))
+}
@@ -0,0 +1,112 @@
+use crate::binding::Binding;
+use crate::simple_property::Requests;
+use druid::{Data, Env, EventCtx, Lens, UpdateCtx};
+use std::marker::PhantomData;
+
+/// A cut down binding for application code: one value, read and written on the widget W,
+/// and the lens to the field of the data T it is bound to.
+/// Wrap an implementation in Simple to use it as a Binding:
+///
+/// ```ignore
+/// struct KnobValue;
+///
+/// impl SimpleBinding<AppState, Knob> for KnobValue {
+///     type Value = f64;
+///     type Lens = app_state_derived_lenses::volume;
+///
+///     fn lens(&self) -> &Self::Lens { &AppState::volume }
+///     fn read(&self, knob: &Knob) -> f64 { knob.value() }
+///     fn write(&self, knob: &mut Knob, value: &f64) { knob.set_value(*value) }
+/// }
+///
+/// knob.binding(Simple::new(KnobValue))
+/// ```
+pub trait SimpleBinding<T, W> {
+    /// The type of the bound value
+    type Value: Data;
+    /// The lens from the data to the bound field
+    type Lens: Lens<T, Self::Value>;
+
+    /// The lens from the data to the bound field
+    fn lens(&self) -> &Self::Lens;
+
+    /// Read the value from the widget
+    fn read(&self, widget: &W) -> Self::Value;
+
+    /// Write the value to the widget
+    fn write(&self, widget: &mut W, value: &Self::Value);
+
+    /// Whether the widget's value is the same as the field's. Changes are checked for on every update,
+    /// so override this when reading the value is expensive, eg allocates a String.
+    fn is_same(&self, widget: &W, value: &Self::Value) -> bool {
+        self.read(widget).same(value)
+    }
+
+    /// What to request of the widget after a write. By default a layout, as it isn't known what the write affects.
+    fn requests(&self) -> Requests {
+        Requests::Layout
+    }
+}
+
+/// Makes a SimpleBinding into a Binding.
+/// A blanket implementation of Binding for every SimpleBinding would overlap with the other
+/// implementations of Binding, so the wrapper is needed.
+pub struct Simple<S, T, W> {
+    simple: S,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<S: SimpleBinding<T, W>, T, W> Simple<S, T, W> {
+    /// Use a SimpleBinding as a Binding
+    pub fn new(simple: S) -> Self {
+        Simple {
+            simple,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<S: SimpleBinding<T, W>, T, W> Binding<T, W> for Simple<S, T, W> {
+    type Change = ();
+
+    fn apply_data_to_controlled(
+        &self,
+        data: &T,
+        controlled: &mut W,
+        ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+        self.simple
+            .lens()
+            .with(data, |value| self.simple.write(controlled, value));
+        self.simple.requests().make(ctx)
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &W,
+        data: &T,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        self.simple.lens().with(data, |value| {
+            if !self.simple.is_same(controlled, value) {
+                *change = Some(())
+            }
+        })
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &W,
+        data: &mut T,
+        _change: Self::Change,
+        _ctx: &mut EventCtx,
+        _env: &Env,
+    ) {
+        let value = self.simple.read(controlled);
+        self.simple.lens().with_mut(data, |field| *field = value)
+    }
+}
@@ -0,0 +1,244 @@
+use crate::binding::BindableProperty;
+use druid::{Data, Env, EventCtx, UpdateCtx};
+use std::marker::PhantomData;
+
+/// A two way bindable property made from a pair of closures, reading and writing a value on the controlled item.
+/// This saves declaring a struct and a BindableProperty impl for an ad-hoc property on an application's own widget:
+///
+/// ```ignore
+/// let font = SimpleValueProperty::new(
+///     |w: &LensedWidget| w.font_name.clone(),
+///     |w: &mut LensedWidget, font: &String| w.font_name = font.clone(),
+/// );
+/// ```
+///
+/// Writes request a layout of the controlled item, as it isn't known what the write affects.
+pub struct SimpleValueProperty<
+    Controlled,
+    Value,
+    Read,
+    Write,
+    Same = fn(&Controlled, &Value) -> bool,
+> {
+    read: Read,
+    write: Write,
+    same: Option<Same>,
+    phantom_c: PhantomData<Controlled>,
+    phantom_v: PhantomData<Value>,
+}
+
+impl<Controlled, Value, Read: Fn(&Controlled) -> Value, Write: Fn(&mut Controlled, &Value)>
+    SimpleValueProperty<Controlled, Value, Read, Write>
+{
+    /// Create a property from a function reading the value from the controlled item, and one writing it.
+    pub fn new(read: Read, write: Write) -> Self {
+        SimpleValueProperty {
+            read,
+            write,
+            same: None,
+            phantom_c: Default::default(),
+            phantom_v: Default::default(),
+        }
+    }
+
+    /// Check for changes with same, which compares the controlled item's value to the field's without reading it out.
+    /// Use this when reading is expensive, eg allocates a String, as changes are checked for on every update.
+    pub fn with_same<Same: Fn(&Controlled, &Value) -> bool>(
+        self,
+        same: Same,
+    ) -> SimpleValueProperty<Controlled, Value, Read, Write, Same> {
+        SimpleValueProperty {
+            read: self.read,
+            write: self.write,
+            same: Some(same),
+            phantom_c: Default::default(),
+            phantom_v: Default::default(),
+        }
+    }
+}
+
+impl<
+        Controlled,
+        Value: Data,
+        Read: Fn(&Controlled) -> Value,
+        Write: Fn(&mut Controlled, &Value),
+        Same: Fn(&Controlled, &Value) -> bool,
+    > BindableProperty for SimpleValueProperty<Controlled, Value, Read, Write, Same>
+{
+    type Controlled = Controlled;
+    type Value = Value;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        (self.write)(controlled, field_val);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        let same = match &self.same {
+            Some(same) => same(controlled, field_val),
+            None => (self.read)(controlled).same(field_val),
+        };
+        if !same {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = (self.read)(controlled)
+    }
+}
+
+/// What a property asks of the controlled widget after a value has been written to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requests {
+    /// The write doesn't affect how the widget looks
+    Nothing,
+    /// The widget needs repainting
+    Paint,
+    /// The widget needs laying out (and so repainting)
+    Layout,
+}
+
+impl Requests {
+    pub(crate) fn make(self, ctx: &mut UpdateCtx) {
+        match self {
+            Requests::Nothing => (),
+            Requests::Paint => ctx.request_paint(),
+            Requests::Layout => ctx.request_layout(),
+        }
+    }
+}
+
+/// A two way bindable property made from plain functions reading and writing a value on a widget W.
+/// Unlike SimpleValueProperty its type can be named, eg in a field or a function's return type,
+/// so it suits one-off bindings to third party widgets:
+///
+/// ```ignore
+/// let value: ClosureProperty<Knob, f64> =
+///     ClosureProperty::new(|k| k.value(), |k, v| k.set_value(*v), Requests::Paint);
+/// ```
+pub struct ClosureProperty<W, V> {
+    read: fn(&W) -> V,
+    write: fn(&mut W, &V),
+    same: Option<fn(&W, &V) -> bool>,
+    requests: Requests,
+}
+
+impl<W, V> ClosureProperty<W, V> {
+    /// Create a property from a function reading the value from the widget, one writing it,
+    /// and what to request of the widget after a write.
+    pub fn new(read: fn(&W) -> V, write: fn(&mut W, &V), requests: Requests) -> Self {
+        ClosureProperty {
+            read,
+            write,
+            same: None,
+            requests,
+        }
+    }
+
+    /// Check for changes with same, which compares the widget's value to the field's without reading it out.
+    /// Use this when reading is expensive, eg allocates a String, as changes are checked for on every update.
+    pub fn with_same(mut self, same: fn(&W, &V) -> bool) -> Self {
+        self.same = Some(same);
+        self
+    }
+}
+
+impl<W, V: Data> BindableProperty for ClosureProperty<W, V> {
+    type Controlled = W;
+    type Value = V;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        (self.write)(controlled, field_val);
+        self.requests.make(ctx)
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        let same = match self.same {
+            Some(same) => same(controlled, field_val),
+            None => (self.read)(controlled).same(field_val),
+        };
+        if !same {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = (self.read)(controlled)
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{detect_change, MockWidget};
+
+    #[test]
+    fn closure_properties_check_with_same_rather_than_reading() {
+        let prop = ClosureProperty::<MockWidget<String>, String>::new(
+            |_| unreachable!("the value is compared with same, not read out"),
+            |w, v| w.value = v.clone(),
+            Requests::Layout,
+        )
+        .with_same(|w, v| w.value == *v);
+        let widget = MockWidget::new("text".to_string());
+        let env = Env::empty();
+        assert!(detect_change(&prop, &widget, &"text".to_string(), &env).is_none());
+        assert!(detect_change(&prop, &widget, &"other".to_string(), &env).is_some());
+    }
+
+    #[test]
+    fn simple_value_properties_check_with_same_rather_than_reading() {
+        let prop = SimpleValueProperty::new(
+            |_: &MockWidget<String>| -> String {
+                unreachable!("the value is compared with same, not read out")
+            },
+            |w: &mut MockWidget<String>, v: &String| w.value = v.clone(),
+        )
+        .with_same(|w, v| w.value == *v);
+        let widget = MockWidget::new("text".to_string());
+        let env = Env::empty();
+        assert!(detect_change(&prop, &widget, &"text".to_string(), &env).is_none());
+        assert!(detect_change(&prop, &widget, &"other".to_string(), &env).is_some());
+    }
+}
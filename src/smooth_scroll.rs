@@ -0,0 +1,222 @@
+use crate::binding::BindableProperty;
+use druid::widget::prelude::*;
+use druid::widget::{Axis, Scroll};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// How an animated scroll progresses over its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Starts fast and slows into the target
+    EaseOut,
+    /// Speeds up then slows into the target
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map linear progress in 0..=1 to eased progress in 0..=1
+    pub fn apply(self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1. - (1. - t) * (1. - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(2) / 2.
+                }
+            }
+        }
+    }
+}
+
+struct ScrollAnimation {
+    axis: Axis,
+    from: f64,
+    to: f64,
+    elapsed: Duration,
+    duration: Duration,
+    easing: Easing,
+}
+
+/// A Scroll that can animate its offset towards a target over a number of animation frames.
+/// Bind it with SmoothScrollToProperty to make programmatic scrolling glide rather than jump.
+pub struct SmoothScroll<T, W> {
+    scroll: Scroll<T, W>,
+    animations: Vec<ScrollAnimation>,
+}
+
+impl<T, W: Widget<T>> SmoothScroll<T, W> {
+    /// Wrap a scroll to allow its offsets to be animated
+    pub fn new(scroll: Scroll<T, W>) -> Self {
+        SmoothScroll {
+            scroll,
+            animations: Vec::new(),
+        }
+    }
+
+    /// Access the wrapped scroll
+    pub fn scroll(&self) -> &Scroll<T, W> {
+        &self.scroll
+    }
+
+    /// Mutably access the wrapped scroll
+    pub fn scroll_mut(&mut self) -> &mut Scroll<T, W> {
+        &mut self.scroll
+    }
+
+    /// Start animating the offset on an axis towards position.
+    /// An animation already running on that axis is interrupted, and the new one starts from wherever it got to.
+    /// Call request_anim_frame on the context afterwards to get the animation going.
+    /// With a zero duration the offset jumps to position on the next animation frame.
+    pub fn animate_to_on_axis(
+        &mut self,
+        axis: Axis,
+        position: f64,
+        duration: Duration,
+        easing: Easing,
+    ) {
+        self.animations.retain(|anim| anim.axis != axis);
+        self.animations.push(ScrollAnimation {
+            axis,
+            from: self.scroll.offset_for_axis(axis),
+            to: position,
+            elapsed: Duration::from_secs(0),
+            duration,
+            easing,
+        });
+    }
+
+    /// Whether an animation is in progress on the given axis
+    pub fn is_animating(&self, axis: Axis) -> bool {
+        self.animations.iter().any(|anim| anim.axis == axis)
+    }
+
+    fn step_animations(&mut self, ctx: &mut EventCtx, interval: u64) {
+        let scroll = &mut self.scroll;
+        self.animations.retain(|anim| {
+            let anim_elapsed = anim.elapsed + Duration::from_nanos(interval);
+            let t = if anim.duration == Duration::from_secs(0) {
+                1.
+            } else {
+                (anim_elapsed.as_secs_f64() / anim.duration.as_secs_f64()).min(1.)
+            };
+            let position = anim.from + (anim.to - anim.from) * anim.easing.apply(t);
+            scroll.scroll_to_on_axis(ctx, anim.axis, position);
+            t < 1.
+        });
+        for anim in &mut self.animations {
+            anim.elapsed += Duration::from_nanos(interval);
+        }
+    }
+}
+
+impl<T, W> crate::BindableAccess for SmoothScroll<T, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for SmoothScroll<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::AnimFrame(interval) if !self.animations.is_empty() => {
+                self.step_animations(ctx, *interval);
+                if !self.animations.is_empty() {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+            // The user taking hold of the scroll interrupts any animation
+            Event::Wheel(_) | Event::MouseDown(_) => self.animations.clear(),
+            _ => (),
+        }
+        self.scroll.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.scroll.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.scroll.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.scroll.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.scroll.paint(ctx, data, env)
+    }
+}
+
+/// A bindable property animating a SmoothScroll's offset on one axis to the bound position.
+/// While an animation runs the intermediate offsets are not written back to the data,
+/// so the bound value stays at the target. A new target arriving mid-animation takes over from the current offset.
+pub struct SmoothScrollToProperty<T, W> {
+    direction: Axis,
+    duration: Duration,
+    easing: Easing,
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> SmoothScrollToProperty<T, W> {
+    /// Create a smooth scroll to property for the specified axis, animating over duration with easing.
+    pub fn new(direction: Axis, duration: Duration, easing: Easing) -> Self {
+        SmoothScrollToProperty {
+            direction,
+            duration,
+            easing,
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> BindableProperty for SmoothScrollToProperty<T, W> {
+    type Controlled = SmoothScroll<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        position: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.animate_to_on_axis(self.direction, *position, self.duration, self.easing);
+        ctx.request_anim_frame();
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.is_animating(self.direction)
+            && !controlled
+                .scroll()
+                .offset_for_axis(self.direction)
+                .same(field_val)
+        {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.scroll().offset_for_axis(self.direction)
+    }
+}
@@ -0,0 +1,159 @@
+use crate::binding::{BindableProperty, Binding};
+use druid::{Env, EventCtx, UpdateCtx};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A store of bound values kept outside of the app's data, keyed by name,
+/// so that widget state like scroll positions can be remembered between runs
+/// without mirroring it into the app's own persisted model.
+///
+/// Clones share the same store. Bind properties to it with `bind`, save it with `to_json`
+/// when the app closes, and load it with `from_json` when it starts.
+#[derive(Clone, Default)]
+pub struct BindingSnapshot {
+    values: Rc<RefCell<Map<String, Value>>>,
+}
+
+impl BindingSnapshot {
+    /// An empty snapshot, for the first run
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Load a snapshot previously saved with to_json. Anything other than an object gives an empty snapshot.
+    pub fn from_json(json: Value) -> Self {
+        let values = match json {
+            Value::Object(values) => values,
+            _ => Map::new(),
+        };
+        BindingSnapshot {
+            values: Rc::new(RefCell::new(values)),
+        }
+    }
+
+    /// The current values, to be saved
+    pub fn to_json(&self) -> Value {
+        Value::Object(self.values.borrow().clone())
+    }
+
+    /// The value stored under name, if there is one and it can be read as a V
+    pub fn get<V: DeserializeOwned>(&self, name: &str) -> Option<V> {
+        self.values
+            .borrow()
+            .get(name)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Store value under name
+    pub fn set<V: Serialize>(&self, name: &str, value: &V) {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.values.borrow_mut().insert(name.to_string(), value);
+            }
+            Err(e) => log::warn!("could not snapshot bound value '{}': {}", name, e),
+        }
+    }
+
+    /// Bind a property to the value stored under name
+    pub fn bind<P: BindableProperty>(
+        &self,
+        name: impl Into<String>,
+        prop: P,
+    ) -> SnapshotBinding<P> {
+        SnapshotBinding::new(self.clone(), name, prop)
+    }
+}
+
+/// Binds a property to a value in a BindingSnapshot rather than to the data.
+/// The stored value is written to the widget when its host is added,
+/// and each change from the widget is stored back.
+/// It can be combined with bindings to the data like any other binding, under any data type.
+pub struct SnapshotBinding<P: BindableProperty> {
+    snapshot: BindingSnapshot,
+    name: String,
+    prop: P,
+    last: RefCell<Option<P::Value>>,
+}
+
+impl<P: BindableProperty> SnapshotBinding<P> {
+    /// Bind prop to the value stored under name in snapshot
+    pub fn new(snapshot: BindingSnapshot, name: impl Into<String>, prop: P) -> Self {
+        SnapshotBinding {
+            snapshot,
+            name: name.into(),
+            prop,
+            last: RefCell::new(None),
+        }
+    }
+}
+
+impl<T, P: BindableProperty> Binding<T, P::Controlled> for SnapshotBinding<P>
+where
+    P::Value: Serialize + DeserializeOwned + Default,
+{
+    type Change = P::Change;
+
+    fn apply_data_to_controlled(
+        &self,
+        _data: &T,
+        _controlled: &mut P::Controlled,
+        _ctx: &mut UpdateCtx,
+        _env: &Env,
+    ) {
+    }
+
+    fn append_change_required(
+        &self,
+        controlled: &P::Controlled,
+        _data: &T,
+        change: &mut Option<Self::Change>,
+        env: &Env,
+    ) {
+        let mut last = self.last.borrow_mut();
+        let last = last.get_or_insert_with(Default::default);
+        self.prop.append_changes(controlled, last, change, env)
+    }
+
+    fn apply_change_to_data(
+        &self,
+        controlled: &P::Controlled,
+        _data: &mut T,
+        change: Self::Change,
+        ctx: &mut EventCtx,
+        env: &Env,
+    ) {
+        let mut last = self.last.borrow_mut();
+        let last = last.get_or_insert_with(Default::default);
+        self.prop
+            .update_data_from_change(controlled, ctx, last, change, env);
+        self.snapshot.set(&self.name, last)
+    }
+
+    fn env_dependent(&self) -> bool {
+        self.prop.env_dependent()
+    }
+
+    fn wants_initial_data(&self) -> bool {
+        true
+    }
+
+    fn apply_initial_data_to_controlled(
+        &self,
+        _data: &T,
+        controlled: &mut P::Controlled,
+        ctx: &mut UpdateCtx,
+        env: &Env,
+    ) {
+        if let Some(value) = self.snapshot.get(&self.name) {
+            self.prop.write_prop(controlled, ctx, &value, env);
+            self.last.replace(Some(value));
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("SnapshotBinding({:?}: {})", self.name, self.prop.name())
+    }
+}
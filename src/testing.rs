@@ -0,0 +1,430 @@
+use crate::binding::{BindableProperty, Binding};
+use druid::widget::prelude::*;
+#[cfg(feature = "druid-master")]
+use druid::{tests::harness::Harness, Selector};
+use druid::{Data, Lens};
+#[cfg(feature = "druid-master")]
+use std::cell::{Cell, Ref, RefCell, RefMut};
+#[cfg(feature = "druid-master")]
+use std::rc::Rc;
+
+/// A minimal bindable leaf widget holding a single value.
+/// It lays out to nothing and paints nothing, so it can stand in as the Controlled item
+/// when checking a property or binding outside of a real widget tree.
+#[derive(Lens, Debug, Clone, Default)]
+pub struct MockWidget<V> {
+    /// The widget state that properties read and write
+    pub value: V,
+}
+
+impl<V> MockWidget<V> {
+    /// Create a mock widget holding the given value
+    pub fn new(value: V) -> Self {
+        MockWidget { value }
+    }
+}
+
+impl<V> crate::BindableAccess for MockWidget<V> {
+    bindable_self_body!();
+}
+
+impl<T, V> Widget<T> for MockWidget<V> {
+    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut T, _env: &Env) {}
+
+    fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &T, _env: &Env) {}
+
+    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {}
+
+    fn layout(&mut self, _ctx: &mut LayoutCtx, bc: &BoxConstraints, _data: &T, _env: &Env) -> Size {
+        bc.min()
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _data: &T, _env: &Env) {}
+}
+
+/// A property exposing the value of a MockWidget.
+/// This is the simplest possible two way property, and doubles as a reference implementation.
+pub struct MockValueProperty<V>(std::marker::PhantomData<V>);
+
+impl<V> Default for MockValueProperty<V> {
+    fn default() -> Self {
+        MockValueProperty(Default::default())
+    }
+}
+
+impl<V: Data> BindableProperty for MockValueProperty<V> {
+    type Controlled = MockWidget<V>;
+    type Value = V;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.value = field_val.clone();
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.value.same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.value.clone()
+    }
+}
+
+/// Run change detection for a property against a controlled item and a field value.
+/// Returns the change the property accrued, if any.
+///
+/// Writing and applying changes need a druid context which only exists inside a running widget tree,
+/// but change detection does not, and it is where most mistakes in custom properties show up.
+/// A PropertyHarness runs the rest of a property inside druid's test Harness.
+pub fn detect_change<P: BindableProperty>(
+    prop: &P,
+    controlled: &P::Controlled,
+    field_val: &P::Value,
+    env: &Env,
+) -> Option<P::Change> {
+    let mut change = None;
+    prop.append_changes(controlled, field_val, &mut change, env);
+    change
+}
+
+/// Run change detection for a binding against a controlled item and the data, as detect_change does for a property.
+/// Returns the change the binding accrued, if any.
+pub fn detect_binding_change<T, C, B: Binding<T, C>>(
+    binding: &B,
+    controlled: &C,
+    data: &T,
+    env: &Env,
+) -> Option<B::Change> {
+    let mut change = None;
+    binding.append_change_required(controlled, data, &mut change, env);
+    change
+}
+
+/// Assert that the property reports a change when the field value differs from the controlled item.
+pub fn assert_change_detected<P: BindableProperty>(
+    prop: &P,
+    controlled: &P::Controlled,
+    field_val: &P::Value,
+    env: &Env,
+) where
+    P::Value: std::fmt::Debug,
+{
+    assert!(
+        detect_change(prop, controlled, field_val, env).is_some(),
+        "expected a change to be detected against field value {:?}",
+        field_val
+    );
+}
+
+/// Assert that the property reports no change when the field value already matches the controlled item.
+/// A property failing this will ping-pong updates between data and widget forever.
+pub fn assert_no_change<P: BindableProperty>(
+    prop: &P,
+    controlled: &P::Controlled,
+    field_val: &P::Value,
+    env: &Env,
+) where
+    P::Value: std::fmt::Debug,
+{
+    assert!(
+        detect_change(prop, controlled, field_val, env).is_none(),
+        "expected no change to be detected against field value {:?}",
+        field_val
+    );
+}
+
+/// Assert that a controlled item whose state was set to `value` (via `set`) is seen as in sync with a field
+/// holding that value, and out of sync with a field holding `other`.
+/// This is the change detection half of a round trip: data written to the widget will not echo back.
+pub fn assert_roundtrip<P: BindableProperty>(
+    prop: &P,
+    controlled: &mut P::Controlled,
+    set: impl FnOnce(&mut P::Controlled, &P::Value),
+    value: &P::Value,
+    other: &P::Value,
+    env: &Env,
+) where
+    P::Value: std::fmt::Debug,
+{
+    set(controlled, value);
+    assert_no_change(prop, controlled, value, env);
+    assert_change_detected(prop, controlled, other, env);
+}
+
+/// The controlled item a PropertyHarness drives, and the value waiting to be written to the field,
+/// shared between the harness and the widget it runs in druid's test Harness
+#[cfg(feature = "druid-master")]
+struct DriverState<P: BindableProperty> {
+    controlled: RefCell<P::Controlled>,
+    pending: RefCell<Option<P::Value>>,
+    found_change: Cell<bool>,
+}
+
+#[cfg(feature = "druid-master")]
+const WRITE_PENDING: Selector = Selector::new("druid-bindings.testing.write-pending");
+#[cfg(feature = "druid-master")]
+const READ_BACK: Selector = Selector::new("druid-bindings.testing.read-back");
+
+/// The root widget of a PropertyHarness, calling the property with the contexts the Harness gives it
+#[cfg(feature = "druid-master")]
+struct PropertyDriver<P: BindableProperty> {
+    prop: P,
+    state: Rc<DriverState<P>>,
+    initial_pending: bool,
+}
+
+#[cfg(feature = "druid-master")]
+impl<P: BindableProperty> Widget<P::Value> for PropertyDriver<P>
+where
+    P::Value: Data,
+{
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut P::Value, env: &Env) {
+        match event {
+            Event::Command(c) if c.is(WRITE_PENDING) => {
+                if let Some(value) = self.state.pending.borrow_mut().take() {
+                    *data = value
+                }
+                // Write the initial value even if it is all there is to write
+                ctx.request_update()
+            }
+            Event::Command(c) if c.is(READ_BACK) => {
+                let controlled = self.state.controlled.borrow();
+                let change = detect_change(&self.prop, &controlled, data, env);
+                self.state.found_change.set(change.is_some());
+                if let Some(change) = change {
+                    self.prop
+                        .update_data_from_change(&controlled, ctx, data, change, env)
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        _ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        _data: &P::Value,
+        _env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.initial_pending = true
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &P::Value, data: &P::Value, env: &Env) {
+        if self.initial_pending || !old_data.same(data) {
+            self.initial_pending = false;
+            self.prop
+                .write_prop(&mut self.state.controlled.borrow_mut(), ctx, data, env)
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &P::Value,
+        _env: &Env,
+    ) -> Size {
+        bc.min()
+    }
+
+    fn paint(&mut self, _ctx: &mut PaintCtx, _data: &P::Value, _env: &Env) {}
+}
+
+/// Drives a property against its controlled item inside druid's test Harness, which provides the contexts
+/// that write_prop and update_data_from_change need. The Harness's data is the bound field.
+///
+/// ```ignore
+/// PropertyHarness::run(MockValueProperty::default(), MockWidget::new(0), 1, |harness| {
+///     assert_eq!(harness.controlled().value, 1);
+///     harness.controlled_mut().value = 2;
+///     assert!(harness.read_back());
+///     assert_eq!(*harness.field(), 2);
+/// });
+/// ```
+#[cfg(feature = "druid-master")]
+pub struct PropertyHarness<'h, 'a, P: BindableProperty> {
+    harness: &'h mut Harness<'a, P::Value>,
+    state: Rc<DriverState<P>>,
+}
+
+#[cfg(feature = "druid-master")]
+impl<'h, 'a, P: BindableProperty + 'static> PropertyHarness<'h, 'a, P>
+where
+    P::Value: Data,
+    P::Controlled: 'static,
+{
+    /// Run test with prop controlling controlled, bound to a field starting out as initial.
+    /// The initial value is written to controlled before test runs, as a BindingHost does when it is added.
+    pub fn run(
+        prop: P,
+        controlled: P::Controlled,
+        initial: P::Value,
+        test: impl FnOnce(&mut PropertyHarness<P>),
+    ) {
+        let state = Rc::new(DriverState {
+            controlled: RefCell::new(controlled),
+            pending: RefCell::new(None),
+            found_change: Cell::new(false),
+        });
+        let driver = PropertyDriver {
+            prop,
+            state: state.clone(),
+            initial_pending: false,
+        };
+        let mut test = Some(test);
+        Harness::create_simple(initial, driver, |harness| {
+            harness.send_initial_events();
+            harness.submit_command(WRITE_PENDING);
+            if let Some(test) = test.take() {
+                test(&mut PropertyHarness {
+                    harness,
+                    state: state.clone(),
+                })
+            }
+        })
+    }
+
+    /// The bound field
+    pub fn field(&self) -> &P::Value {
+        self.harness.data()
+    }
+
+    /// The controlled item, eg to check what write_prop did to it
+    pub fn controlled(&self) -> Ref<'_, P::Controlled> {
+        self.state.controlled.borrow()
+    }
+
+    /// The controlled item, mutably, eg to change its state as a user would before read_back
+    pub fn controlled_mut(&self) -> RefMut<'_, P::Controlled> {
+        self.state.controlled.borrow_mut()
+    }
+
+    /// Set the bound field to value, which writes it to the controlled item if it differs, as an update would
+    pub fn write(&mut self, value: P::Value) {
+        self.state.pending.replace(Some(value));
+        self.harness.submit_command(WRITE_PENDING)
+    }
+
+    /// Look for a change from the controlled item, and apply it to the field if there is one.
+    /// Returns whether there was a change.
+    pub fn read_back(&mut self) -> bool {
+        self.state.found_change.set(false);
+        self.harness.submit_command(READ_BACK);
+        self.state.found_change.get()
+    }
+}
+
+/// Set the value of a MockWidget under an EditMock, standing in for an edit by the user in tests of hosts
+#[cfg(all(test, feature = "druid-master"))]
+pub(crate) const EDIT_MOCK: Selector<i32> = Selector::new("druid-bindings.testing.edit-mock");
+
+/// Controls a MockWidget, setting its value on EDIT_MOCK and recording the value it has after each update
+#[cfg(all(test, feature = "druid-master"))]
+#[derive(Default)]
+pub(crate) struct EditMock {
+    pub(crate) seen: Rc<Cell<i32>>,
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+impl<T> druid::widget::Controller<T, MockWidget<i32>> for EditMock {
+    fn event(
+        &mut self,
+        child: &mut MockWidget<i32>,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut T,
+        env: &Env,
+    ) {
+        if let Event::Command(cmd) = event {
+            if let Some(value) = cmd.get(EDIT_MOCK) {
+                child.value = *value
+            }
+        }
+        child.event(ctx, event, data, env)
+    }
+
+    fn update(
+        &mut self,
+        child: &mut MockWidget<i32>,
+        ctx: &mut UpdateCtx,
+        old_data: &T,
+        data: &T,
+        env: &Env,
+    ) {
+        child.update(ctx, old_data, data, env);
+        self.seen.set(child.value)
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harness_writes_the_initial_value() {
+        PropertyHarness::run(
+            MockValueProperty::default(),
+            MockWidget::new(0),
+            1,
+            |harness| {
+                assert_eq!(harness.controlled().value, 1);
+                assert_eq!(*harness.field(), 1);
+            },
+        );
+    }
+
+    #[test]
+    fn harness_writes_the_field_to_the_controlled_item() {
+        PropertyHarness::run(
+            MockValueProperty::default(),
+            MockWidget::new(0),
+            1,
+            |harness| {
+                harness.write(5);
+                assert_eq!(harness.controlled().value, 5);
+                assert_eq!(*harness.field(), 5);
+            },
+        );
+    }
+
+    #[test]
+    fn harness_reads_changes_back_to_the_field() {
+        PropertyHarness::run(
+            MockValueProperty::default(),
+            MockWidget::new(0),
+            1,
+            |harness| {
+                assert!(!harness.read_back());
+                harness.controlled_mut().value = 7;
+                assert!(harness.read_back());
+                assert_eq!(*harness.field(), 7);
+                assert!(!harness.read_back());
+            },
+        );
+    }
+}
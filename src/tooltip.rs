@@ -0,0 +1,193 @@
+use druid::widget::prelude::*;
+use druid::{theme, Insets, Point, TextLayout, TimerToken, Vec2};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+const TOOLTIP_GAP: f64 = 4.;
+const TOOLTIP_PADDING: f64 = 4.;
+
+/// Wraps a widget, showing a tooltip under it once the mouse has rested on it for the show delay.
+/// Druid has no tooltips of its own, so this gives hint text that can be bound to app state with
+/// TooltipTextProperty and TooltipDelayProperty. Empty text shows no tooltip.
+///
+/// The tooltip is painted above other widgets, but within the window, so it can be cut off at its edges.
+pub struct BindableTooltip<T, W> {
+    inner: W,
+    text: TextLayout<String>,
+    delay: Duration,
+    timer: TimerToken,
+    showing: bool,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> BindableTooltip<T, W> {
+    /// Show text as a tooltip for inner, after half a second
+    pub fn new(inner: W, text: impl Into<String>) -> Self {
+        let mut layout = TextLayout::new();
+        layout.set_text(text.into());
+        layout.set_text_size(theme::TEXT_SIZE_NORMAL);
+        BindableTooltip {
+            inner,
+            text: layout,
+            delay: Duration::from_millis(500),
+            timer: TimerToken::INVALID,
+            showing: false,
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The tooltip text
+    pub fn text(&self) -> &str {
+        self.text.text().map(|text| text.as_str()).unwrap_or("")
+    }
+
+    /// Set the tooltip text
+    pub fn set_text(&mut self, text: String) {
+        self.text.set_text(text)
+    }
+
+    /// How long the mouse must rest on the widget before the tooltip shows
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Set how long the mouse must rest on the widget before the tooltip shows
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay
+    }
+
+    fn hide(&mut self, ctx: &mut EventCtx) {
+        self.timer = TimerToken::INVALID;
+        if self.showing {
+            self.showing = false;
+            ctx.request_paint();
+        }
+    }
+}
+
+impl<T, W> crate::BindableAccess for BindableTooltip<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for BindableTooltip<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseMove(_) if ctx.is_hot() && !self.showing => {
+                self.timer = ctx.request_timer(self.delay)
+            }
+            Event::MouseMove(_) if !ctx.is_hot() => self.hide(ctx),
+            Event::MouseDown(_) | Event::Wheel(_) => self.hide(ctx),
+            Event::Timer(token) if *token == self.timer => {
+                self.timer = TimerToken::INVALID;
+                self.showing = true;
+                ctx.request_layout();
+                ctx.request_paint();
+                ctx.set_handled();
+                return;
+            }
+            _ => (),
+        }
+        self.inner.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::HotChanged(false) = event {
+            self.timer = TimerToken::INVALID;
+            if self.showing {
+                self.showing = false;
+                ctx.request_paint();
+            }
+        }
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if ctx.env_changed() && self.text.needs_rebuild_after_update(ctx) {
+            ctx.request_layout();
+        }
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let size = self.inner.layout(ctx, bc, data, env);
+        self.text.rebuild_if_needed(ctx.text(), env);
+        // Make room to paint the tooltip outside of the widget
+        let tip = self.text.size();
+        ctx.set_paint_insets(Insets::new(
+            0.,
+            0.,
+            (tip.width + 2. * TOOLTIP_PADDING - size.width).max(0.),
+            tip.height + 2. * TOOLTIP_PADDING + TOOLTIP_GAP,
+        ));
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env);
+        if self.showing && !self.text().is_empty() {
+            let text = self.text.clone();
+            let origin = Point::new(0., ctx.size().height + TOOLTIP_GAP);
+            let background = env.get(theme::BACKGROUND_LIGHT);
+            let border = env.get(theme::BORDER_DARK);
+            ctx.paint_with_z_index(1_000_000, move |ctx| {
+                let rect = (text.size() + Size::new(2. * TOOLTIP_PADDING, 2. * TOOLTIP_PADDING))
+                    .to_rect()
+                    .with_origin(origin);
+                ctx.fill(rect, &background);
+                ctx.stroke(rect, &border, 1.);
+                text.draw(ctx, origin + Vec2::new(TOOLTIP_PADDING, TOOLTIP_PADDING));
+            });
+        }
+    }
+}
+
+bindable_props! {
+    impl<T, W: Widget<T>> for BindableTooltip<T, W> {
+        /// A write-only property for the text of a BindableTooltip
+        pub TooltipTextProperty: String => set_text, request_layout;
+        /// A write-only property for how long the mouse must rest before a BindableTooltip shows
+        pub TooltipDelayProperty: Duration => set_delay, request_paint;
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockWidget, PropertyHarness};
+
+    fn tooltip() -> BindableTooltip<String, MockWidget<()>> {
+        BindableTooltip::new(MockWidget::new(()), "hint")
+    }
+
+    #[test]
+    fn the_tooltip_text_is_written_to_the_widget() {
+        PropertyHarness::run(
+            TooltipTextProperty::default(),
+            tooltip(),
+            "Save".to_string(),
+            |harness| {
+                assert_eq!(harness.controlled().text(), "Save");
+                harness.write("Save all".into());
+                assert_eq!(harness.controlled().text(), "Save all");
+                // The tooltip is write-only, so nothing is read back from it
+                harness.controlled_mut().set_text("changed".into());
+                assert!(!harness.read_back());
+                assert_eq!(harness.field(), "Save all");
+            },
+        );
+    }
+
+    #[test]
+    fn the_tooltip_delay_is_written_to_the_widget() {
+        PropertyHarness::run(
+            TooltipDelayProperty::default(),
+            BindableTooltip::<Duration, _>::new(MockWidget::new(()), "hint"),
+            Duration::from_secs(1),
+            |harness| {
+                assert_eq!(harness.controlled().delay(), Duration::from_secs(1));
+                harness.write(Duration::from_millis(100));
+                assert_eq!(harness.controlled().delay(), Duration::from_millis(100));
+            },
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use druid::widget::prelude::*;
+use std::marker::PhantomData;
+
+/// Wraps a widget, usually a Scroll, scaling the mouse wheel and trackpad deltas it receives.
+/// Druid's Scroll has no setting for its scroll speed. Binding WheelSensitivityProperty to this wrapper
+/// lets user settings, eg accessibility preferences, drive it centrally.
+pub struct WheelSensitivity<T, W> {
+    inner: W,
+    sensitivity: f64,
+    phantom_t: PhantomData<T>,
+}
+
+impl<T, W: Widget<T>> WheelSensitivity<T, W> {
+    /// Pass wheel deltas to inner unchanged, until a sensitivity is set
+    pub fn new(inner: W) -> Self {
+        WheelSensitivity {
+            inner,
+            sensitivity: 1.,
+            phantom_t: Default::default(),
+        }
+    }
+
+    /// The multiplier applied to wheel deltas
+    pub fn sensitivity(&self) -> f64 {
+        self.sensitivity
+    }
+
+    /// Set the multiplier applied to wheel deltas
+    pub fn set_sensitivity(&mut self, sensitivity: f64) {
+        self.sensitivity = sensitivity
+    }
+
+    /// Access the wrapped widget
+    pub fn inner(&self) -> &W {
+        &self.inner
+    }
+
+    /// Mutably access the wrapped widget
+    pub fn inner_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+
+impl<T, W> crate::BindableAccess for WheelSensitivity<T, W> {
+    bindable_self_body!();
+}
+
+impl<T, W: Widget<T>> Widget<T> for WheelSensitivity<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::Wheel(mouse) if self.sensitivity != 1. => {
+                let mut mouse = mouse.clone();
+                mouse.wheel_delta *= self.sensitivity;
+                self.inner.event(ctx, &Event::Wheel(mouse), data, env)
+            }
+            _ => self.inner.event(ctx, event, data, env),
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        self.inner.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env)
+    }
+}
+
+bindable_props! {
+    impl<T, W: Widget<T>> for WheelSensitivity<T, W> {
+        /// A write-only property for the multiplier a WheelSensitivity applies to wheel deltas
+        pub WheelSensitivityProperty: f64 => set_sensitivity, request_paint;
+    }
+}
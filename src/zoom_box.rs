@@ -0,0 +1,298 @@
+use crate::binding::BindableProperty;
+use crate::compat;
+use druid::widget::prelude::*;
+use druid::{Affine, Data, MouseEvent, Point, WidgetPod};
+use std::marker::PhantomData;
+
+/// Shows its child scaled about an anchor, the point of the child shown at the centre of the box.
+/// Like a ClipBox the child is laid out at its natural size and clipped to the box, but the view
+/// can be scaled as well as moved. Ctrl and the mouse wheel zoom it, within the scale range.
+///
+/// Bind ZoomScaleProperty and ZoomAnchorProperty to drive it from data, eg for map or diagram viewers
+/// alongside the scroll bindings. Paint invalidations from inside the child are not transformed,
+/// so the whole box is repainted when the child is interacted with.
+pub struct ZoomBox<T, W> {
+    child: WidgetPod<T, W>,
+    scale: f64,
+    anchor: Point,
+    min_scale: f64,
+    max_scale: f64,
+}
+
+impl<T, W: Widget<T>> ZoomBox<T, W> {
+    /// Show child unscaled, anchored at its origin
+    pub fn new(child: W) -> Self {
+        ZoomBox {
+            child: WidgetPod::new(child),
+            scale: 1.,
+            anchor: Point::ORIGIN,
+            min_scale: 0.1,
+            max_scale: 10.,
+        }
+    }
+
+    /// Limit the scale to between min and max, including when zooming with the wheel
+    pub fn with_scale_range(mut self, min: f64, max: f64) -> Self {
+        self.min_scale = min;
+        self.max_scale = max;
+        self.scale = self.scale.max(min).min(max);
+        self
+    }
+
+    /// The current scale factor
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Set the scale factor, limited to the scale range
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = scale.max(self.min_scale).min(self.max_scale)
+    }
+
+    /// The point of the child shown at the centre of the box
+    pub fn anchor(&self) -> Point {
+        self.anchor
+    }
+
+    /// Set the point of the child shown at the centre of the box
+    pub fn set_anchor(&mut self, anchor: Point) {
+        self.anchor = anchor
+    }
+
+    /// Maps points in the child to points in the box
+    fn transform(&self, size: Size) -> Affine {
+        Affine::translate(size.to_vec2() / 2.)
+            * Affine::scale(self.scale)
+            * Affine::translate(-self.anchor.to_vec2())
+    }
+
+    fn to_child(&self, size: Size, mouse: &MouseEvent) -> MouseEvent {
+        let mut mouse = mouse.clone();
+        mouse.pos = self.transform(size).inverse() * mouse.pos;
+        mouse
+    }
+}
+
+impl<T, W> crate::BindableAccess for ZoomBox<T, W> {
+    bindable_self_body!();
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for ZoomBox<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let size = ctx.size();
+        let child_event = match event {
+            Event::Wheel(mouse) if mouse.mods.ctrl() => {
+                self.set_scale(self.scale * (-mouse.wheel_delta.y / 500.).exp());
+                ctx.request_paint();
+                ctx.set_handled();
+                return;
+            }
+            Event::MouseDown(mouse) => Event::MouseDown(self.to_child(size, mouse)),
+            Event::MouseUp(mouse) => Event::MouseUp(self.to_child(size, mouse)),
+            Event::MouseMove(mouse) => Event::MouseMove(self.to_child(size, mouse)),
+            Event::Wheel(mouse) => Event::Wheel(self.to_child(size, mouse)),
+            _ => event.clone(),
+        };
+        self.child.event(ctx, &child_event, data, env);
+        if let Event::MouseDown(_) | Event::MouseUp(_) | Event::MouseMove(_) | Event::Wheel(_) =
+            event
+        {
+            ctx.request_paint();
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env)
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        let child_bc = BoxConstraints::new(Size::ZERO, Size::new(f64::INFINITY, f64::INFINITY));
+        let child_size = self.child.layout(ctx, &child_bc, data, env);
+        compat::set_origin(&mut self.child, ctx, data, env, Point::ORIGIN);
+        bc.constrain(child_size * self.scale)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let size = ctx.size();
+        let transform = self.transform(size);
+        ctx.clip(size.to_rect());
+        ctx.with_save(|ctx| {
+            ctx.transform(transform);
+            self.child.paint_raw(ctx, data, env)
+        })
+    }
+}
+
+/// A bindable property for the scale factor of a ZoomBox
+pub struct ZoomScaleProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ZoomScaleProperty<T, W> {
+    fn default() -> Self {
+        ZoomScaleProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for ZoomScaleProperty<T, W> {
+    type Controlled = ZoomBox<T, W>;
+    type Value = f64;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_scale(*field_val);
+        ctx.request_layout()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if !controlled.scale().same(field_val) {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.scale()
+    }
+}
+
+/// A bindable property for the anchor of a ZoomBox, the point of its child shown at its centre
+pub struct ZoomAnchorProperty<T, W> {
+    phantom_t: PhantomData<T>,
+    phantom_w: PhantomData<W>,
+}
+
+impl<T, W> Default for ZoomAnchorProperty<T, W> {
+    fn default() -> Self {
+        ZoomAnchorProperty {
+            phantom_t: Default::default(),
+            phantom_w: Default::default(),
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> BindableProperty for ZoomAnchorProperty<T, W> {
+    type Controlled = ZoomBox<T, W>;
+    type Value = Point;
+    type Change = ();
+
+    fn write_prop(
+        &self,
+        controlled: &mut Self::Controlled,
+        ctx: &mut UpdateCtx,
+        field_val: &Self::Value,
+        _env: &Env,
+    ) {
+        controlled.set_anchor(*field_val);
+        ctx.request_paint()
+    }
+
+    fn append_changes(
+        &self,
+        controlled: &Self::Controlled,
+        field_val: &Self::Value,
+        change: &mut Option<Self::Change>,
+        _env: &Env,
+    ) {
+        if controlled.anchor() != *field_val {
+            *change = Some(())
+        }
+    }
+
+    fn update_data_from_change(
+        &self,
+        controlled: &Self::Controlled,
+        _ctx: &EventCtx,
+        field: &mut Self::Value,
+        _change: Self::Change,
+        _env: &Env,
+    ) {
+        *field = controlled.anchor()
+    }
+}
+
+#[cfg(all(test, feature = "druid-master"))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockWidget, PropertyHarness};
+
+    fn zoom_box() -> ZoomBox<(), MockWidget<()>> {
+        ZoomBox::new(MockWidget::new(())).with_scale_range(0.5, 4.)
+    }
+
+    #[test]
+    fn the_scale_is_written_and_read_back() {
+        PropertyHarness::run(ZoomScaleProperty::default(), zoom_box(), 2., |harness| {
+            assert_eq!(harness.controlled().scale(), 2.);
+            assert!(!harness.read_back());
+            harness.write(3.);
+            assert_eq!(harness.controlled().scale(), 3.);
+            // As zooming with the wheel would
+            harness.controlled_mut().set_scale(1.5);
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 1.5);
+            assert!(!harness.read_back());
+        });
+    }
+
+    #[test]
+    fn scales_past_the_range_are_clamped_and_echoed_back() {
+        PropertyHarness::run(ZoomScaleProperty::default(), zoom_box(), 1., |harness| {
+            harness.write(10.);
+            assert_eq!(harness.controlled().scale(), 4.);
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 4.);
+            harness.write(0.1);
+            assert_eq!(harness.controlled().scale(), 0.5);
+            assert!(harness.read_back());
+            assert_eq!(*harness.field(), 0.5);
+        });
+    }
+
+    #[test]
+    fn the_anchor_is_written_and_read_back() {
+        PropertyHarness::run(
+            ZoomAnchorProperty::default(),
+            zoom_box(),
+            Point::new(10., 20.),
+            |harness| {
+                assert_eq!(harness.controlled().anchor(), Point::new(10., 20.));
+                harness.controlled_mut().set_anchor(Point::new(5., 5.));
+                assert!(harness.read_back());
+                assert_eq!(*harness.field(), Point::new(5., 5.));
+            },
+        );
+    }
+
+    #[test]
+    fn narrowing_the_range_clamps_the_scale() {
+        let zoom_box = ZoomBox::<(), _>::new(MockWidget::new(())).with_scale_range(2., 4.);
+        assert_eq!(zoom_box.scale(), 2.);
+    }
+}